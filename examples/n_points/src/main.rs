@@ -31,6 +31,7 @@ use std::io::Write;
 use clap::arg;
 use clap::command;
 
+use d3_delaunay_rs::bounds::Bounds;
 use d3_delaunay_rs::delaunay::Delaunay;
 use geo_types::Coord;
 use rand::Rng;
@@ -74,7 +75,8 @@ fn main() -> std::io::Result<()> {
     file.write_all(data_delaunay.as_bytes())?;
     file.write_all(b"\"/>\n")?;
 
-    let voronoi = delaunay.voronoi(Some((0_f64, 0_f64, 100_f64, 100_f64)));
+    let voronoi =
+        delaunay.voronoi(Some(Bounds::new(0_f64, 0_f64, 100_f64, 100_f64)));
     // Output voronoi mesh. ( blue )
     let data_voronoi = voronoi.render_to_string();
     file.write_all(
@@ -9,15 +9,18 @@ use web_sys::OffscreenCanvasRenderingContext2d;
 use web_sys::Performance;
 use web_sys::PerformanceMeasure;
 
+use d3_delaunay_rs::bounds::Bounds;
 use d3_delaunay_rs::delaunay::Delaunay;
+use d3_delaunay_rs::relax::Density;
+use d3_delaunay_rs::relax::Relax;
+use d3_delaunay_rs::voronoi::Voronoi;
 
 #[wasm_bindgen]
 pub struct Stippler {
     width: usize,
     height: usize,
-    n: usize,
     data: Vec<f64>,
-    delaunay: Delaunay<f64>,
+    voronoi: Voronoi<f64>,
     context: OffscreenCanvasRenderingContext2d,
 }
 
@@ -65,7 +68,12 @@ impl Stippler {
 
         console_log!("rejection sampling {:#?} ms", measure.duration());
 
-        let delaunay = Delaunay::new(&points);
+        let voronoi = Delaunay::new(&points).voronoi(Some(Bounds::new(
+            0_f64,
+            0_f64,
+            width as f64,
+            height as f64,
+        )));
 
         performance.mark("initial_voronoi_complete")?;
 
@@ -83,9 +91,8 @@ impl Stippler {
         let state = Stippler {
             width,
             height,
-            n,
             data,
-            delaunay,
+            voronoi,
             context: context.clone(),
         };
         Ok(state)
@@ -93,50 +100,27 @@ impl Stippler {
 
     pub fn next(&mut self, k: usize) -> Result<(), JsValue> {
         // Compute the weighted centroid for each Voronoi cell.
-        let mut c: Vec<Coord<f64>> = Vec::with_capacity(self.n);
-        let mut s: Vec<f64> = Vec::with_capacity(self.n);
-        for _i in 0..self.n {
-            c.push(Coord { x: 0_f64, y: 0_f64 });
-            s.push(0_f64);
-        }
-
-        let mut i = 0;
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let w = self.data[y * self.width + x];
-                i = self.delaunay.find(
-                    &Coord {
-                        x: x as f64 + 0.5_f64,
-                        y: y as f64 + 0.5_f64,
-                    },
-                    Some(i),
-                );
-                s[i] += w;
-                c[i].x += w * (x as f64 + 0.5_f64);
-                c[i].y += w * (y as f64 + 0.5_f64);
-            }
-        }
+        let relax = Relax {
+            density: Density::Raster {
+                data: &self.data,
+                width: self.width,
+                height: self.height,
+            },
+            ..Relax::default()
+        };
+        let c = relax.centroids(&self.voronoi);
 
         // Relax the diagram by moving points to the weighted centroid.
         // Wiggle the points a little bit so they don’t get stuck.
         let w = (k as f64 + 1_f64).powf(-0.8) * 10_f64;
-        for i in 0..self.n {
-            let x0 = self.delaunay.points[i].x;
-            let y0 = self.delaunay.points[i].y;
-            let x1 = if s[i] == 0_f64 { x0 } else { c[i].x / s[i] };
-            let y1 = if s[i] == 0_f64 { y0 } else { c[i].y / s[i] };
-
-            self.delaunay.points[i].x =
-                x0 + (x1 - x0) * 1.8 + (random() - 0.5) * w;
-            self.delaunay.points[i].y =
-                y0 + (y1 - y0) * 1.8 + (random() - 0.5) * w;
+        for (p, c) in self.voronoi.delaunay.points.iter_mut().zip(c) {
+            p.x += (c.x - p.x) * 1.8 + (random() - 0.5) * w;
+            p.y += (c.y - p.y) * 1.8 + (random() - 0.5) * w;
         }
 
         self.draw()?;
 
-        // // TODO: doing a update() the hard way...
-        // // What can I refactor here.
-        self.delaunay = Delaunay::new(&self.delaunay.points);
+        self.voronoi.update();
 
         Ok(())
     }
@@ -152,7 +136,7 @@ impl Stippler {
         );
 
         self.context.begin_path();
-        for p in &self.delaunay.points {
+        for p in &self.voronoi.delaunay.points {
             self.context.move_to(p.x + 1.5_f64, p.y);
             self.context.arc(
                 p.x,
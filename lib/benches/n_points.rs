@@ -7,6 +7,7 @@ mod static_points;
 
 use core::time::Duration;
 use criterion::Criterion;
+use d3_delaunay_rs::bounds::Bounds;
 use d3_delaunay_rs::delaunay::Delaunay;
 use static_points::POINTS;
 
@@ -22,8 +23,8 @@ fn criterion_benchmark(c: &mut Criterion) {
         b.iter(|| {
             let delaunay = Delaunay::new(&POINTS);
             assert!(delaunay.delaunator.triangles.len() > 500);
-            let voronoi =
-                delaunay.voronoi(Some((0_f64, 0_f64, 100_f64, 100_f64)));
+            let voronoi = delaunay
+                .voronoi(Some(Bounds::new(0_f64, 0_f64, 100_f64, 100_f64)));
 
             assert!(voronoi.circumcenters.len() > 50);
         })
@@ -0,0 +1,83 @@
+#[macro_use]
+extern crate criterion;
+extern crate pretty_assertions;
+extern crate rand;
+
+mod static_points;
+
+use criterion::Criterion;
+use d3_delaunay_rs::delaunay::Delaunay;
+use geo_types::Coord;
+
+use static_points::POINTS;
+
+// The queries, a grid of 10 * 10 points across the static points.
+fn queries() -> Vec<Coord<f64>> {
+    (0..100)
+        .map(|i| Coord {
+            x: 5_f64 + 10_f64 * f64::from(i % 10),
+            y: 5_f64 + 10_f64 * f64::from(i / 10),
+        })
+        .collect()
+}
+
+// Sorts every point by distance, the nearest first.
+fn brute_force(p: &Coord<f64>) -> Vec<(f64, usize)> {
+    let mut all: Vec<(f64, usize)> = POINTS
+        .iter()
+        .enumerate()
+        .map(|(i, q)| ((q.x - p.x).hypot(q.y - p.y), i))
+        .collect();
+    all.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    all
+}
+
+// 100 queries over 1000 points, against sorting every point.
+fn criterion_benchmark(c: &mut Criterion) {
+    let delaunay = Delaunay::new(&POINTS);
+    let queries = queries();
+
+    let mut g = c.benchmark_group("nearest");
+
+    g.bench_function("k_nearest", |b| {
+        b.iter(|| {
+            for p in &queries {
+                assert_eq!(delaunay.k_nearest(p, 10, None).len(), 10);
+            }
+        })
+    });
+
+    g.bench_function("k_nearest brute force", |b| {
+        b.iter(|| {
+            for p in &queries {
+                let nearest: Vec<usize> =
+                    brute_force(p).iter().take(10).map(|(_, i)| *i).collect();
+                assert_eq!(nearest.len(), 10);
+            }
+        })
+    });
+
+    g.bench_function("within_radius", |b| {
+        b.iter(|| {
+            for p in &queries {
+                assert!(!delaunay.within_radius(p, 5_f64, None).is_empty());
+            }
+        })
+    });
+
+    g.bench_function("within_radius brute force", |b| {
+        b.iter(|| {
+            for p in &queries {
+                let within: Vec<usize> = brute_force(p)
+                    .iter()
+                    .take_while(|(d, _)| *d <= 5_f64)
+                    .map(|(_, i)| *i)
+                    .collect();
+                assert!(!within.is_empty());
+            }
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -7,8 +7,8 @@ mod static_points;
 
 use core::time::Duration;
 use criterion::Criterion;
+use d3_delaunay_rs::bounds::Bounds;
 use d3_delaunay_rs::delaunay::Delaunay;
-use geo_types::Coord;
 
 use static_points::POINTS;
 
@@ -23,9 +23,9 @@ fn criterion_benchmark(c: &mut Criterion) {
 
     g.bench_function("update", |b| {
         b.iter(|| {
-            let mut delaunay = Delaunay::new(&POINTS);
-            let mut voronoi =
-                delaunay.voronoi(Some((0_f64, 0_f64, 100_f64, 100_f64)));
+            let delaunay = Delaunay::new(&POINTS);
+            let mut voronoi = delaunay
+                .voronoi(Some(Bounds::new(0_f64, 0_f64, 100_f64, 100_f64)));
 
             // 1000 call to update
             for _i in 0..1000 {
@@ -37,11 +37,7 @@ fn criterion_benchmark(c: &mut Criterion) {
                     pair[3].y -= 1_f64;
                 }
 
-                // Before update is implement voronoi must be
-                // recomputed from scratch.
-                delaunay = Delaunay::new(&voronoi.delaunay.points);
-                voronoi =
-                    delaunay.voronoi(Some((0_f64, 0_f64, 100_f64, 100_f64)));
+                voronoi.update();
 
                 assert!(voronoi.delaunay.delaunator.triangles.len() > 500);
             }
@@ -0,0 +1,212 @@
+//! The clipping extent of a voronoi diagram.
+//!
+
+use geo::CoordFloat;
+use geo_types::Coord;
+use geo_types::Rect;
+
+use crate::error::DelaunayError;
+
+/// An axis aligned rectangle: xmin, ymin, xmax, ymax.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bounds<T>
+where
+    T: CoordFloat,
+{
+    /// Left edge.
+    pub xmin: T,
+    /// Top edge.
+    pub ymin: T,
+    /// Right edge.
+    pub xmax: T,
+    /// Bottom edge.
+    pub ymax: T,
+}
+
+impl<T> Default for Bounds<T>
+where
+    T: CoordFloat,
+{
+    /// The default extent used by d3-delaunay, [0, 0, 960, 500].
+    ///
+    /// # Panics
+    ///  Will never happen as constants will always be converted into T.
+    fn default() -> Self {
+        Self {
+            xmin: T::zero(),
+            ymin: T::zero(),
+            xmax: T::from(960_f64).unwrap(),
+            ymax: T::from(500_f64).unwrap(),
+        }
+    }
+}
+
+impl<T> Bounds<T>
+where
+    T: CoordFloat,
+{
+    /// Constructs bounds from its components.
+    ///
+    /// # Panics
+    ///  When xmax < xmin, ymax < ymin or a component is not finite,
+    ///  see [`Bounds::try_new`].
+    #[must_use]
+    pub fn new(xmin: T, ymin: T, xmax: T, ymax: T) -> Self {
+        Self::try_new(xmin, ymin, xmax, ymax).expect("Invalid bounds")
+    }
+
+    /// Constructs bounds from its components.
+    ///
+    /// # Errors
+    /// [`DelaunayError::InvalidBounds`] if the bounds are reversed or not finite.
+    pub fn try_new(
+        xmin: T,
+        ymin: T,
+        xmax: T,
+        ymax: T,
+    ) -> Result<Self, DelaunayError> {
+        let bounds = Self {
+            xmin,
+            ymin,
+            xmax,
+            ymax,
+        };
+        if bounds.is_valid() {
+            Ok(bounds)
+        } else {
+            Err(DelaunayError::InvalidBounds)
+        }
+    }
+
+    /// Constructs bounds from the top-left and bottom-right corners.
+    ///
+    /// # Errors
+    /// [`DelaunayError::InvalidBounds`] if the bounds are reversed or not finite.
+    pub fn from_corners(
+        min: Coord<T>,
+        max: Coord<T>,
+    ) -> Result<Self, DelaunayError> {
+        Self::try_new(min.x, min.y, max.x, max.y)
+    }
+
+    /// Returns the bounding box of the points, grown by margin on each side.
+    ///
+    /// Returns None when there are no points, or a coordinate is not finite.
+    pub fn from_points(points: &[Coord<T>], margin: T) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut bounds = Self {
+            xmin: first.x,
+            ymin: first.y,
+            xmax: first.x,
+            ymax: first.y,
+        };
+        for p in rest {
+            bounds.xmin = bounds.xmin.min(p.x);
+            bounds.ymin = bounds.ymin.min(p.y);
+            bounds.xmax = bounds.xmax.max(p.x);
+            bounds.ymax = bounds.ymax.max(p.y);
+        }
+        let bounds = bounds.expand(margin);
+        // NB min() and max() skip over NaN, so check every point.
+        let finite = points.iter().all(|p| p.x.is_finite() && p.y.is_finite());
+        (finite && bounds.is_valid()).then_some(bounds)
+    }
+
+    /// Returns true if the bounds are finite and not reversed.
+    pub fn is_valid(&self) -> bool {
+        let finite = self.xmin.is_finite()
+            && self.ymin.is_finite()
+            && self.xmax.is_finite()
+            && self.ymax.is_finite();
+        // NB: NaN fails both comparisons.
+        finite && self.xmin <= self.xmax && self.ymin <= self.ymax
+    }
+
+    /// The top-left corner.
+    #[inline]
+    pub const fn min(&self) -> Coord<T> {
+        Coord {
+            x: self.xmin,
+            y: self.ymin,
+        }
+    }
+
+    /// The bottom-right corner.
+    #[inline]
+    pub const fn max(&self) -> Coord<T> {
+        Coord {
+            x: self.xmax,
+            y: self.ymax,
+        }
+    }
+
+    /// xmax - xmin.
+    #[inline]
+    pub fn width(&self) -> T {
+        self.xmax - self.xmin
+    }
+
+    /// ymax - ymin.
+    #[inline]
+    pub fn height(&self) -> T {
+        self.ymax - self.ymin
+    }
+
+    /// Returns the midpoint.
+    #[inline]
+    pub fn center(&self) -> Coord<T> {
+        let two = T::one() + T::one();
+        Coord {
+            x: (self.xmin + self.xmax) / two,
+            y: (self.ymin + self.ymax) / two,
+        }
+    }
+
+    /// Returns true if p is inside the bounds, or on its edge.
+    #[inline]
+    pub fn contains(&self, p: &Coord<T>) -> bool {
+        self.xmin <= p.x
+            && p.x <= self.xmax
+            && self.ymin <= p.y
+            && p.y <= self.ymax
+    }
+
+    /// Returns the bounds grown by margin on each side.
+    ///
+    /// A negative margin shrinks the bounds.
+    #[must_use]
+    pub fn expand(&self, margin: T) -> Self {
+        Self {
+            xmin: self.xmin - margin,
+            ymin: self.ymin - margin,
+            xmax: self.xmax + margin,
+            ymax: self.ymax + margin,
+        }
+    }
+}
+
+impl<T> From<Rect<T>> for Bounds<T>
+where
+    T: CoordFloat,
+{
+    #[inline]
+    fn from(rect: Rect<T>) -> Self {
+        Self {
+            xmin: rect.min().x,
+            ymin: rect.min().y,
+            xmax: rect.max().x,
+            ymax: rect.max().y,
+        }
+    }
+}
+
+impl<T> From<Bounds<T>> for Rect<T>
+where
+    T: CoordFloat,
+{
+    #[inline]
+    fn from(bounds: Bounds<T>) -> Self {
+        Self::new(bounds.min(), bounds.max())
+    }
+}
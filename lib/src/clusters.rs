@@ -0,0 +1,120 @@
+//! Clusters of points, the connected components left once the long edges
+//! of the triangulation are cut.
+//!
+//! See [`Delaunay::clusters`].
+
+use geo::CoordFloat;
+use geo_types::Coord;
+use geo_types::MultiPolygon;
+use geo_types::Polygon;
+use num_traits::FloatConst;
+use num_traits::FromPrimitive;
+
+use crate::delaunay::Delaunay;
+
+/// Which edges of the triangulation are cut, see [`Delaunay::clusters`].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cut<T> {
+    /// Cut the edges longer than this length.
+    Length(T),
+    /// Cut the edges longer than this multiple of the local mean, the mean
+    /// length of the edges meeting at either end.
+    ///
+    /// An edge is cut when it is long compared with the edges at either of
+    /// its points, so a dense cluster is split from a sparse neighbour.
+    LocalMean(T),
+}
+
+/// The points of a triangulation, grouped into clusters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Clusters<'a, T>
+where
+    T: CoordFloat,
+{
+    /// The points which are grouped.
+    pub points: &'a [Coord<T>],
+    /// The cluster of each point.
+    ///
+    /// Clusters are numbered from zero in the order of their first point,
+    /// so point 0 is always in cluster 0.
+    pub labels: Vec<usize>,
+    members: Vec<Vec<usize>>,
+}
+
+impl<'a, T> Clusters<'a, T>
+where
+    T: CoordFloat + FloatConst + FromPrimitive,
+{
+    pub(crate) fn new(points: &'a [Coord<T>], labels: Vec<usize>) -> Self {
+        let mut members: Vec<Vec<usize>> = vec![];
+        for (i, label) in labels.iter().enumerate() {
+            if *label == members.len() {
+                members.push(vec![]);
+            }
+            members[*label].push(i);
+        }
+        Self {
+            points,
+            labels,
+            members,
+        }
+    }
+
+    /// Returns the number of clusters.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns true if there are no clusters, as when there are no points.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Returns the indexes of the points in cluster c, in increasing order.
+    ///
+    /// Empty when there is no cluster c.
+    #[must_use]
+    pub fn members(&self, c: usize) -> &[usize] {
+        self.members.get(c).map_or(&[], |m| &m[..])
+    }
+
+    /// Returns the convex hull of cluster c.
+    ///
+    /// see [`Delaunay::hull_line_string`] for a cluster of 1 or 2 distinct
+    /// points. Empty when there is no cluster c.
+    #[must_use]
+    pub fn hull(&self, c: usize) -> Polygon<T> {
+        self.triangulate(c).hull_polygon()
+    }
+
+    /// Returns an iterator over the convex hull of each cluster, in order.
+    pub fn hulls(&self) -> impl Iterator<Item = Polygon<T>> + '_ {
+        (0..self.len()).map(|c| self.hull(c))
+    }
+
+    /// Returns the alpha shape of cluster c, found from the triangulation
+    /// of its points alone.
+    ///
+    /// see [`Delaunay::alpha_shape`]. Empty when there is no cluster c.
+    #[must_use]
+    pub fn alpha_shape(&self, c: usize, alpha: T) -> MultiPolygon<T> {
+        self.triangulate(c).alpha_shape(alpha)
+    }
+
+    /// Returns an iterator over the alpha shape of each cluster, in order.
+    pub fn alpha_shapes(
+        &self,
+        alpha: T,
+    ) -> impl Iterator<Item = MultiPolygon<T>> + '_ {
+        (0..self.len()).map(move |c| self.alpha_shape(c, alpha))
+    }
+
+    fn triangulate(&self, c: usize) -> Delaunay<T> {
+        let points: Vec<Coord<T>> =
+            self.members(c).iter().map(|i| self.points[*i]).collect();
+        Delaunay::new(&points)
+    }
+}
@@ -0,0 +1,198 @@
+//! Alpha shapes, the outline of the points at a chosen level of detail.
+//!
+//! Edelsbrunner, Kirkpatrick and Seidel, "On the shape of a set of points
+//! in the plane", IEEE Transactions on Information Theory (1983).
+//!
+//! A triangle is kept when its circumradius is no greater than alpha. As
+//! alpha grows the shape fills out from scattered triangles to the convex
+//! hull.
+
+use delaunator::next_halfedge;
+use delaunator::EMPTY;
+use geo::CoordFloat;
+use geo_types::Coord;
+use geo_types::LineString;
+use geo_types::MultiPolygon;
+use geo_types::Polygon;
+use num_traits::FloatConst;
+use num_traits::FromPrimitive;
+
+use super::constrain::orient;
+use super::Delaunay;
+use crate::disjoint::DisjointSet;
+use crate::geometry::signed_area;
+
+impl<T> Delaunay<T>
+where
+    T: CoordFloat + FloatConst + FromPrimitive,
+{
+    /// Returns the alpha shape, the union of the triangles whose
+    /// circumradius is no greater than alpha.
+    ///
+    /// Each connected component is a polygon, with a hole for each gap
+    /// enclosed by its triangles. Exterior rings run counterclockwise and
+    /// holes clockwise, with y pointing up. Components which touch at a
+    /// single point are separate polygons.
+    ///
+    /// Empty when there are fewer than three points, or all the points are
+    /// collinear.
+    #[must_use]
+    pub fn alpha_shape(&self, alpha: T) -> MultiPolygon<T> {
+        if self.collinear.is_some() || self.delaunator.hull.len() < 3 {
+            return MultiPolygon::new(vec![]);
+        }
+        let triangles = &self.delaunator.triangles;
+        let halfedges = &self.delaunator.halfedges;
+        let n = triangles.len() / 3;
+        let keep: Vec<bool> =
+            (0..n).map(|t| self.circumradius(t) <= alpha).collect();
+        let kept = |e: usize| e != EMPTY && keep[e / 3];
+
+        // Triangles sharing an edge belong to the same component.
+        let mut components = DisjointSet::new(n);
+        for (e, o) in halfedges.iter().enumerate() {
+            if kept(e) && kept(*o) {
+                components.union(e / 3, o / 3);
+            }
+        }
+
+        // The boundary loops, each with the component on its left.
+        let mut rings: Vec<(usize, Vec<LineString<T>>)> = vec![];
+        let mut visited = vec![false; triangles.len()];
+        for start in 0..triangles.len() {
+            if visited[start] || !kept(start) || kept(halfedges[start]) {
+                continue;
+            }
+            let mut ring = Vec::new();
+            let mut e = start;
+            loop {
+                visited[e] = true;
+                ring.push(self.points[triangles[e]]);
+                // Turn around the end point to the next boundary halfedge.
+                let mut f = next_halfedge(e);
+                while kept(halfedges[f]) {
+                    f = next_halfedge(halfedges[f]);
+                }
+                e = f;
+                if e == start {
+                    break;
+                }
+            }
+            ring.push(ring[0]);
+
+            let root = components.find(start / 3);
+            match rings.iter_mut().find(|(r, _)| *r == root) {
+                Some((_, loops)) => loops.push(ring.into()),
+                None => rings.push((root, vec![ring.into()])),
+            }
+        }
+
+        // A loop running the same way as the triangles is the exterior.
+        let t = self.orientation();
+        let polygons = rings
+            .into_iter()
+            .filter_map(|(_, loops)| {
+                let (mut exterior, mut interiors): (Vec<_>, Vec<_>) =
+                    loops.into_iter().partition(|ring| {
+                        (signed_area(&ring.0) * t).is_sign_positive()
+                    });
+                let mut exterior = exterior.pop()?;
+                if signed_area(&exterior.0) < T::zero() {
+                    exterior.0.reverse();
+                }
+                for ring in &mut interiors {
+                    if signed_area(&ring.0) > T::zero() {
+                        ring.0.reverse();
+                    }
+                }
+                Some(Polygon::new(exterior, interiors))
+            })
+            .collect();
+        MultiPolygon::new(polygons)
+    }
+
+    /// Returns the smallest alpha for which the alpha shape is a single
+    /// polygon holding every point of the triangulation.
+    ///
+    /// The points may lie on the boundary or inside the polygon. None when
+    /// there are fewer than three points, or all the points are collinear.
+    #[must_use]
+    pub fn smallest_connected_alpha(&self) -> Option<T> {
+        if self.collinear.is_some() || self.delaunator.hull.len() < 3 {
+            return None;
+        }
+        let triangles = &self.delaunator.triangles;
+        let halfedges = &self.delaunator.halfedges;
+        let n = triangles.len() / 3;
+        let radii: Vec<T> = (0..n).map(|t| self.circumradius(t)).collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|a, b| {
+            radii[*a]
+                .partial_cmp(&radii[*b])
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+        let mut covered = vec![false; self.points.len()];
+        let mut remaining = 0;
+        for i in triangles {
+            if !covered[*i] {
+                covered[*i] = true;
+                remaining += 1;
+            }
+        }
+        covered.fill(false);
+
+        // Add the triangles from the smallest, merging components as they
+        // come into contact.
+        let mut sets = DisjointSet::new(n);
+        let mut added = vec![false; n];
+        let mut components = 0;
+        for t in order {
+            added[t] = true;
+            components += 1;
+            for e in 3 * t..3 * t + 3 {
+                let o = halfedges[e];
+                if o != EMPTY && added[o / 3] && sets.union(t, o / 3) {
+                    components -= 1;
+                }
+                if !covered[triangles[e]] {
+                    covered[triangles[e]] = true;
+                    remaining -= 1;
+                }
+            }
+            if components == 1 && remaining == 0 {
+                return Some(radii[t]);
+            }
+        }
+        None
+    }
+
+    // The radius of the circumcircle of triangle t, infinite when the
+    // triangle is degenerate.
+    fn circumradius(&self, t: usize) -> T {
+        let [a, b, c] = [0, 1, 2]
+            .map(|k| self.points[self.delaunator.triangles[3 * t + k]]);
+        let o = orient(a, b, c).abs();
+        if o.is_zero() {
+            return T::infinity();
+        }
+        let length = |p: Coord<T>, q: Coord<T>| (p.x - q.x).hypot(p.y - q.y);
+        // abc / 4A, where orient() is twice the area.
+        length(a, b) * length(b, c) * length(c, a) / (o + o)
+    }
+
+    // The sign of the shoelace area of the triangles.
+    fn orientation(&self) -> T {
+        let triangles = &self.delaunator.triangles;
+        (0..triangles.len() / 3)
+            .map(|t| {
+                signed_area(&[
+                    self.points[triangles[3 * t]],
+                    self.points[triangles[3 * t + 1]],
+                    self.points[triangles[3 * t + 2]],
+                ])
+            })
+            .find(|area| !area.is_zero())
+            .map_or_else(T::one, T::signum)
+    }
+}
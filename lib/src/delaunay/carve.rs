@@ -0,0 +1,126 @@
+//! Removes the triangles lying outside a polygon, or inside its holes.
+//!
+//! Each triangle is given a depth, the fewest constrained edges crossed
+//! on a path from outside the convex hull. Triangles at an odd depth lie
+//! inside the polygon.
+
+use std::collections::VecDeque;
+
+use delaunator::next_halfedge;
+use delaunator::Triangulation;
+use delaunator::EMPTY;
+
+/// Keeps only the triangles at an odd depth, and replaces the hull with
+/// the boundary loop passing through `start`.
+///
+/// The constrained halfedges are compacted alongside the triangles.
+pub fn carve(
+    delaunator: &mut Triangulation,
+    constrained: &mut Vec<bool>,
+    start: usize,
+) {
+    let triangles = &delaunator.triangles;
+    let halfedges = &delaunator.halfedges;
+    let n = triangles.len() / 3;
+    if n == 0 || constrained.is_empty() {
+        return;
+    }
+
+    // A 0-1 breadth first search, seeded from the convex hull.
+    let mut depth = vec![usize::MAX; n];
+    let mut queue = VecDeque::new();
+    for (e, he) in halfedges.iter().enumerate() {
+        if *he == EMPTY {
+            let d = usize::from(constrained[e]);
+            if d < depth[e / 3] {
+                depth[e / 3] = d;
+                if d == 0 {
+                    queue.push_front(e / 3);
+                } else {
+                    queue.push_back(e / 3);
+                }
+            }
+        }
+    }
+    while let Some(t) = queue.pop_front() {
+        for e in 3 * t..3 * t + 3 {
+            let o = halfedges[e];
+            if o == EMPTY {
+                continue;
+            }
+            let step = usize::from(constrained[e]);
+            let d = depth[t] + step;
+            if d < depth[o / 3] {
+                depth[o / 3] = d;
+                if step == 0 {
+                    queue.push_front(o / 3);
+                } else {
+                    queue.push_back(o / 3);
+                }
+            }
+        }
+    }
+
+    // The new index of each triangle which is kept.
+    let mut index = vec![EMPTY; n];
+    let mut kept = 0;
+    for (t, d) in depth.iter().enumerate() {
+        if d % 2 == 1 {
+            index[t] = kept;
+            kept += 1;
+        }
+    }
+
+    let mut new_triangles = Vec::with_capacity(3 * kept);
+    let mut new_halfedges = Vec::with_capacity(3 * kept);
+    let mut new_constrained = Vec::with_capacity(3 * kept);
+    for e in 0..triangles.len() {
+        if index[e / 3] == EMPTY {
+            continue;
+        }
+        new_triangles.push(triangles[e]);
+        let o = halfedges[e];
+        new_halfedges.push(if o == EMPTY || index[o / 3] == EMPTY {
+            EMPTY
+        } else {
+            3 * index[o / 3] + o % 3
+        });
+        new_constrained.push(constrained[e]);
+    }
+
+    let hull = boundary(&new_triangles, &new_halfedges, start);
+    delaunator.triangles = new_triangles;
+    delaunator.halfedges = new_halfedges;
+    delaunator.hull = hull;
+    *constrained = new_constrained;
+}
+
+/// Returns the points of the boundary loop passing through `start`, in
+/// the order of its halfedges.
+///
+/// Empty when `start` is not on the boundary.
+pub(super) fn boundary(
+    triangles: &[usize],
+    halfedges: &[usize],
+    start: usize,
+) -> Vec<usize> {
+    let mut hull = Vec::new();
+    if let Some(first) = (0..triangles.len())
+        .find(|e| triangles[*e] == start && halfedges[*e] == EMPTY)
+    {
+        let mut e = first;
+        loop {
+            hull.push(triangles[e]);
+            // Turn around the end point to the next boundary halfedge.
+            let mut f = next_halfedge(e);
+            while halfedges[f] != EMPTY {
+                f = next_halfedge(halfedges[f]);
+            }
+            e = f;
+            if e == first {
+                break;
+            }
+        }
+    }
+    hull
+}
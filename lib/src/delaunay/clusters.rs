@@ -0,0 +1,90 @@
+//! Clustering by cutting the long edges of the triangulation.
+
+use delaunator::EMPTY;
+use geo::CoordFloat;
+use num_traits::FloatConst;
+use num_traits::FromPrimitive;
+
+use super::Delaunay;
+use crate::clusters::Clusters;
+use crate::clusters::Cut;
+use crate::disjoint::DisjointSet;
+use crate::graphs::Graph;
+
+impl<T> Delaunay<T>
+where
+    T: CoordFloat + FloatConst + FromPrimitive,
+{
+    /// Groups the points into clusters, the connected components of the
+    /// triangulation once the edges selected by cut are removed.
+    ///
+    /// A point which coincides with another joins its cluster. When all
+    /// the points are collinear, each point is joined to the next along
+    /// the line, as in [`Graph::delaunay`].
+    ///
+    /// # Panics
+    ///  Will never happen as constants will always be converted into T.
+    #[must_use]
+    pub fn clusters(&self, cut: Cut<T>) -> Clusters<'_, T> {
+        let points = &self.points;
+        let graph = Graph::delaunay(self);
+        let length = |(i, j): (usize, usize)| {
+            let d = points[i] - points[j];
+            d.x.hypot(d.y)
+        };
+
+        let mut sets = DisjointSet::new(points.len());
+        match cut {
+            Cut::Length(max) => {
+                for (i, j) in &graph.edges {
+                    if length((*i, *j)) <= max {
+                        sets.union(*i, *j);
+                    }
+                }
+            }
+            Cut::LocalMean(factor) => {
+                let mut total = vec![T::zero(); points.len()];
+                let mut count = vec![0_usize; points.len()];
+                for (i, j) in &graph.edges {
+                    let l = length((*i, *j));
+                    for k in [*i, *j] {
+                        total[k] = total[k] + l;
+                        count[k] += 1;
+                    }
+                }
+                // Every end of an edge has at least that edge.
+                let mean = |k: usize| total[k] / T::from(count[k]).unwrap();
+                for (i, j) in &graph.edges {
+                    let max = factor * mean(*i).min(mean(*j));
+                    if length((*i, *j)) <= max {
+                        sets.union(*i, *j);
+                    }
+                }
+            }
+        }
+
+        // A coincident point is not in the triangulation.
+        for (i, p) in points.iter().enumerate() {
+            if self.inedges[i] == EMPTY {
+                let j = self.find(p, None);
+                if j != EMPTY && points[j] == *p {
+                    sets.union(i, j);
+                }
+            }
+        }
+
+        // The root of each set is its first point.
+        let mut labels = vec![0; points.len()];
+        let mut len = 0;
+        for i in 0..points.len() {
+            let root = sets.find(i);
+            if root == i {
+                labels[i] = len;
+                len += 1;
+            } else {
+                labels[i] = labels[root];
+            }
+        }
+        Clusters::new(points, labels)
+    }
+}
@@ -4,6 +4,9 @@ use geo_types::Coord;
 use delaunator::Triangulation;
 use num_traits::FromPrimitive;
 
+#[cfg(feature = "robust")]
+use super::constrain::orient;
+
 /// Is the triangulation collinear?
 #[derive(Debug, Eq, PartialEq)]
 pub enum Tri {
@@ -12,6 +15,7 @@ pub enum Tri {
 }
 
 // A triangulation is collinear if all its triangles have a non-null area.
+#[cfg(not(feature = "robust"))]
 pub fn colinear<T>(coords: &[Coord<T>], d: &Triangulation) -> Tri
 where
     T: CoordFloat + FromPrimitive,
@@ -29,3 +33,21 @@ where
     }
     Tri::Collinear
 }
+
+// A triangulation is collinear if none of its triangles has an area, as
+// decided by the exact orientation test.
+#[cfg(feature = "robust")]
+pub fn colinear<T>(coords: &[Coord<T>], d: &Triangulation) -> Tri
+where
+    T: CoordFloat + FromPrimitive,
+{
+    let not_collinear = d
+        .triangles
+        .chunks_exact(3)
+        .any(|t| orient(coords[t[0]], coords[t[1]], coords[t[2]]) > T::zero());
+    if not_collinear {
+        Tri::NotCollinear
+    } else {
+        Tri::Collinear
+    }
+}
@@ -0,0 +1,551 @@
+//! Inserts constraint edges into a triangulation by edge flipping.
+//!
+//! Sloan, "A fast algorithm for generating constrained Delaunay
+//! triangulations", Computers & Structures 47 (1993).
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use delaunator::next_halfedge;
+use delaunator::prev_halfedge;
+use delaunator::Triangulation;
+use delaunator::EMPTY;
+use geo::CoordFloat;
+use geo_types::Coord;
+
+use crate::error::DelaunayError;
+
+/// Forces each constraint into the triangulation, marking the
+/// halfedges that lie on a constraint.
+///
+/// A constraint which fails is skipped, the first failure is returned
+/// once the remaining constraints have been inserted.
+pub fn constrain<T>(
+    delaunator: &mut Triangulation,
+    constrained: &mut Vec<bool>,
+    constraints: &[(usize, usize)],
+    coords: &[Coord<T>],
+) -> Result<(), DelaunayError>
+where
+    T: CoordFloat,
+{
+    constrained.clear();
+    if constraints.is_empty() {
+        return Ok(());
+    }
+
+    let mut result = Ok(());
+    let mut fail = |e| {
+        if result.is_ok() {
+            result = Err(e);
+        }
+    };
+
+    // An outgoing halfedge for each point, maintained as edges are flipped.
+    let mut out = vec![EMPTY; coords.len()];
+    for (e, p) in delaunator.triangles.iter().enumerate() {
+        out[*p] = e;
+    }
+
+    let mut mesh = Mesh {
+        delaunator,
+        coords: Cow::Borrowed(coords),
+        out,
+        fixed: HashSet::new(),
+    };
+    for (k, (p, q)) in constraints.iter().enumerate() {
+        if *p >= coords.len() || *q >= coords.len() || p == q {
+            fail(DelaunayError::InvalidConstraint(k));
+            continue;
+        }
+        // Nothing to constrain in the degenerate case of 1 or 2 points.
+        if mesh.delaunator.triangles.is_empty() {
+            continue;
+        }
+        // A coincident point is missing from the triangulation.
+        if mesh.out[*p] == EMPTY || mesh.out[*q] == EMPTY {
+            fail(DelaunayError::InvalidConstraint(k));
+            continue;
+        }
+        if let Err(e) = mesh.insert(k, *p, *q) {
+            fail(e);
+        }
+    }
+
+    if !mesh.fixed.is_empty() {
+        let triangles = &mesh.delaunator.triangles;
+        constrained.extend((0..triangles.len()).map(|e| {
+            mesh.fixed
+                .contains(&key(triangles[e], triangles[next_halfedge(e)]))
+        }));
+    }
+
+    result
+}
+
+/// Flips the edges of the triangulation until every edge passes the exact
+/// in-circle test, and no triangle is inverted.
+///
+/// delaunator decides which edges to flip with floating point arithmetic,
+/// so for nearly cocircular points the result may not be delaunay, and a
+/// triangle may be left inverted or without area. Such a triangle is
+/// repaired by flipping an edge it shares with a neighbor. Flips which
+/// would leave a triangle without area are skipped, so a bad triangle with
+/// no flippable edge, as on the hull, is kept.
+#[cfg(feature = "robust")]
+pub fn legalize<T>(delaunator: &mut Triangulation, coords: &[Coord<T>])
+where
+    T: CoordFloat,
+{
+    let mut out = vec![EMPTY; coords.len()];
+    for (e, p) in delaunator.triangles.iter().enumerate() {
+        out[*p] = e;
+    }
+    let mut mesh = Mesh {
+        delaunator,
+        coords: Cow::Borrowed(coords),
+        out,
+        fixed: HashSet::new(),
+    };
+
+    let mut stack: Vec<usize> = (0..mesh.delaunator.halfedges.len()).collect();
+    while let Some(a) = stack.pop() {
+        let b = mesh.delaunator.halfedges[a];
+        if b == EMPTY {
+            continue;
+        }
+        let triangles = &mesh.delaunator.triangles;
+        let pr = triangles[a];
+        let pl = triangles[next_halfedge(a)];
+        let p0 = triangles[prev_halfedge(a)];
+        let p1 = triangles[prev_halfedge(b)];
+        // The in-circle test is only meaningful for triangles of the right
+        // orientation. Every flip leaves two such triangles, so the number of
+        // bad triangles never grows.
+        let bad = mesh.orient(pr, pl, p0) <= T::zero()
+            || mesh.orient(pl, pr, p1) <= T::zero();
+        if (bad || mesh.in_circle(pr, pl, p0, p1))
+            && mesh.orient(p1, pl, p0) > T::zero()
+            && mesh.orient(p0, pr, p1) > T::zero()
+        {
+            mesh.flip(a);
+            // The outer edges of the quadrilateral.
+            stack.extend([a, next_halfedge(a), b, next_halfedge(b)]);
+        }
+    }
+}
+
+/// A triangulation being modified in place.
+pub(super) struct Mesh<'a, T>
+where
+    T: CoordFloat,
+{
+    pub(super) delaunator: &'a mut Triangulation,
+    // Owned when points are being added.
+    pub(super) coords: Cow<'a, [Coord<T>]>,
+    // An outgoing halfedge for each point.
+    pub(super) out: Vec<usize>,
+    // The constrained edges, as ordered pairs of points.
+    pub(super) fixed: HashSet<(usize, usize)>,
+}
+
+impl<T> Mesh<'_, T>
+where
+    T: CoordFloat,
+{
+    fn insert(
+        &mut self,
+        k: usize,
+        mut p: usize,
+        q: usize,
+    ) -> Result<(), DelaunayError> {
+        // Split the constraint at each point lying on it.
+        while p != q {
+            let end = self.insert_segment(k, p, q)?;
+            self.fixed.insert(key(p, end));
+            p = end;
+        }
+        Ok(())
+    }
+
+    // Makes an edge from p towards q, stopping at the first point
+    // on the segment. Returns the point reached.
+    fn insert_segment(
+        &mut self,
+        k: usize,
+        p: usize,
+        q: usize,
+    ) -> Result<usize, DelaunayError> {
+        let triangles = &self.delaunator.triangles;
+        let halfedges = &self.delaunator.halfedges;
+
+        // Find the triangle around p that the segment leaves through.
+        let mut crossing = None;
+        for e in self.around(p) {
+            let b = triangles[next_halfedge(e)];
+            let c = triangles[prev_halfedge(e)];
+            for v in [b, c] {
+                if v == q || self.on_segment(p, q, v) {
+                    return Ok(v);
+                }
+            }
+            if self.orient(p, b, q) > T::zero()
+                && self.orient(p, q, c) > T::zero()
+            {
+                crossing = Some(next_halfedge(e));
+                break;
+            }
+        }
+        let Some(mut x) = crossing else {
+            return Err(DelaunayError::InvalidConstraint(k));
+        };
+
+        // Walk towards q, collecting the edges that cross the segment. The
+        // segment crosses each triangle at most once.
+        let mut edges = VecDeque::new();
+        let mut steps = triangles.len() / 3;
+        let end = loop {
+            let u = triangles[x];
+            let v = triangles[next_halfedge(x)];
+            if self.fixed.contains(&key(u, v)) {
+                return Err(DelaunayError::IntersectingConstraint(k));
+            }
+            edges.push_back((u, v));
+
+            // Leaving the triangulation, or going round in circles, is
+            // only possible through rounding.
+            let t = halfedges[x];
+            if t == EMPTY || steps == 0 {
+                return Err(DelaunayError::UnresolvedConstraint(k));
+            }
+            steps -= 1;
+            let d = triangles[prev_halfedge(t)];
+            let side = self.orient(p, q, d);
+            if d == q || side.is_zero() {
+                break d;
+            }
+            // t runs from v to u.
+            x = if (side > T::zero()) == (self.orient(p, q, u) > T::zero()) {
+                prev_halfedge(t)
+            } else {
+                next_halfedge(t)
+            };
+        };
+
+        // Flip the crossing edges until none remain. Without rounding each
+        // pass over the queue flips at least one edge.
+        let mut created = vec![];
+        let mut stalled = 0;
+        while let Some((u, v)) = edges.pop_front() {
+            let (e, o) = self.twins(k, u, v)?;
+            let p0 = self.delaunator.triangles[prev_halfedge(e)];
+            let p1 = self.delaunator.triangles[prev_halfedge(o)];
+            // Only a convex quadrilateral can be flipped, try again later.
+            let su = self.orient(p0, p1, u);
+            let sv = self.orient(p0, p1, v);
+            if su.is_zero()
+                || sv.is_zero()
+                || (su > T::zero()) == (sv > T::zero())
+            {
+                if stalled > edges.len() {
+                    return Err(DelaunayError::UnresolvedConstraint(k));
+                }
+                stalled += 1;
+                edges.push_back((u, v));
+                continue;
+            }
+            stalled = 0;
+            self.flip(e);
+            if self.crosses(p, end, p0, p1) {
+                edges.push_back((p0, p1));
+            } else {
+                created.push((p0, p1));
+            }
+        }
+
+        self.restore(k, key(p, end), created)?;
+        Ok(end)
+    }
+
+    // Restores the Delaunay condition around the edges created while
+    // inserting constraint k, leaving the constrained edge alone. Each pass
+    // flips at least one edge, rounding aside.
+    fn restore(
+        &mut self,
+        k: usize,
+        fixed: (usize, usize),
+        mut created: Vec<(usize, usize)>,
+    ) -> Result<(), DelaunayError> {
+        let mut flipped = true;
+        let mut passes = created.len() * created.len() + 1;
+        while flipped {
+            if passes == 0 {
+                return Err(DelaunayError::UnresolvedConstraint(k));
+            }
+            passes -= 1;
+            flipped = false;
+            for edge in &mut created {
+                let (u, v) = *edge;
+                if key(u, v) == fixed {
+                    continue;
+                }
+                let Some(e) = self.edge(u, v) else {
+                    return Err(DelaunayError::UnresolvedConstraint(k));
+                };
+                let o = self.delaunator.halfedges[e];
+                if o == EMPTY {
+                    continue;
+                }
+                let triangles = &self.delaunator.triangles;
+                // e may run from v to u.
+                let a = triangles[e];
+                let b = triangles[next_halfedge(e)];
+                let p0 = triangles[prev_halfedge(e)];
+                let p1 = triangles[prev_halfedge(o)];
+                if self.in_circle(a, b, p0, p1) {
+                    self.flip(e);
+                    *edge = (p0, p1);
+                    flipped = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The outgoing halfedges of point p.
+    pub(super) fn around(&self, p: usize) -> Vec<usize> {
+        let halfedges = &self.delaunator.halfedges;
+        let start = self.out[p];
+        let mut edges = vec![start];
+        let mut e = start;
+        loop {
+            e = halfedges[prev_halfedge(e)];
+            if e == EMPTY {
+                break;
+            }
+            if e == start {
+                return edges;
+            }
+            edges.push(e);
+        }
+        // p is on the hull, turn the other way from the start.
+        e = start;
+        loop {
+            let t = halfedges[e];
+            if t == EMPTY {
+                return edges;
+            }
+            e = next_halfedge(t);
+            edges.push(e);
+        }
+    }
+
+    // A halfedge joining u and v.
+    pub(super) fn edge(&self, u: usize, v: usize) -> Option<usize> {
+        let triangles = &self.delaunator.triangles;
+        self.around(u).into_iter().find_map(|e| {
+            if triangles[next_halfedge(e)] == v {
+                Some(e)
+            } else if triangles[prev_halfedge(e)] == v {
+                Some(prev_halfedge(e))
+            } else {
+                None
+            }
+        })
+    }
+
+    // The two halfedges of an interior edge joining u and v, for inserting
+    // constraint k.
+    fn twins(
+        &self,
+        k: usize,
+        u: usize,
+        v: usize,
+    ) -> Result<(usize, usize), DelaunayError> {
+        self.edge(u, v)
+            .map(|e| (e, self.delaunator.halfedges[e]))
+            .filter(|(_, o)| *o != EMPTY)
+            .ok_or(DelaunayError::UnresolvedConstraint(k))
+    }
+
+    // Flips the diagonal of the quadrilateral formed by halfedge a and its
+    // twin, as in delaunator's legalize().
+    pub(super) fn flip(&mut self, a: usize) {
+        let triangles = &mut self.delaunator.triangles;
+        let halfedges = &mut self.delaunator.halfedges;
+        let b = halfedges[a];
+        let al = next_halfedge(a);
+        let ar = prev_halfedge(a);
+        let br = next_halfedge(b);
+        let bl = prev_halfedge(b);
+
+        let p0 = triangles[ar];
+        let p1 = triangles[bl];
+        triangles[a] = p1;
+        triangles[b] = p0;
+
+        let hbl = halfedges[bl];
+        let har = halfedges[ar];
+        link(halfedges, a, hbl);
+        link(halfedges, b, har);
+        link(halfedges, ar, bl);
+
+        self.out[p0] = ar;
+        self.out[p1] = bl;
+        self.out[triangles[al]] = al;
+        self.out[triangles[br]] = br;
+    }
+
+    pub(super) fn orient(&self, a: usize, b: usize, c: usize) -> T {
+        orient(self.coords[a], self.coords[b], self.coords[c])
+    }
+
+    pub(super) fn in_circle(
+        &self,
+        a: usize,
+        b: usize,
+        c: usize,
+        p: usize,
+    ) -> bool {
+        in_circle(
+            self.coords[a],
+            self.coords[b],
+            self.coords[c],
+            self.coords[p],
+        )
+    }
+
+    // Does v lie on the segment from p to q, excluding the ends?
+    fn on_segment(&self, p: usize, q: usize, v: usize) -> bool {
+        if v == p || !self.orient(p, q, v).is_zero() {
+            return false;
+        }
+        let (p, q, v) = (self.coords[p], self.coords[q], self.coords[v]);
+        let dot = (v.x - p.x) * (q.x - p.x) + (v.y - p.y) * (q.y - p.y);
+        let len2 = (q.x - p.x) * (q.x - p.x) + (q.y - p.y) * (q.y - p.y);
+        T::zero() < dot && dot < len2
+    }
+
+    // Do the segments p-q and u-v cross at a point interior to both?
+    fn crosses(&self, p: usize, q: usize, u: usize, v: usize) -> bool {
+        let opposite = |a: T, b: T| {
+            (a > T::zero() && b < T::zero()) || (a < T::zero() && b > T::zero())
+        };
+        opposite(self.orient(p, q, u), self.orient(p, q, v))
+            && opposite(self.orient(u, v, p), self.orient(u, v, q))
+    }
+}
+
+/// Positive when a, b, c have the same orientation as the triangles.
+#[cfg(not(feature = "robust"))]
+pub fn orient<T>(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> T
+where
+    T: CoordFloat,
+{
+    (c.x - a.x) * (b.y - a.y) - (b.x - a.x) * (c.y - a.y)
+}
+
+/// Positive when a, b, c have the same orientation as the triangles.
+///
+/// The sign is exact, the magnitude is as accurate as a double allows.
+#[cfg(feature = "robust")]
+pub fn orient<T>(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> T
+where
+    T: CoordFloat,
+{
+    // robust orients with y pointing up, the negative of orient().
+    let o = -robust::orient2d(exact(a), exact(b), exact(c));
+    T::from(o).unwrap_or_else(T::nan)
+}
+
+/// Is p inside the circumcircle of triangle a, b, c?
+#[cfg(not(feature = "robust"))]
+pub(super) fn in_circle<T>(
+    a: Coord<T>,
+    b: Coord<T>,
+    c: Coord<T>,
+    p: Coord<T>,
+) -> bool
+where
+    T: CoordFloat,
+{
+    let dx = a.x - p.x;
+    let dy = a.y - p.y;
+    let ex = b.x - p.x;
+    let ey = b.y - p.y;
+    let fx = c.x - p.x;
+    let fy = c.y - p.y;
+
+    let ap = dx * dx + dy * dy;
+    let bp = ex * ex + ey * ey;
+    let cp = fx * fx + fy * fy;
+
+    dx * (ey * cp - bp * fy) - dy * (ex * cp - bp * fx)
+        + ap * (ex * fy - ey * fx)
+        < T::zero()
+}
+
+/// Is p inside the circumcircle of triangle a, b, c?
+///
+/// The answer is exact.
+#[cfg(feature = "robust")]
+pub(super) fn in_circle<T>(
+    a: Coord<T>,
+    b: Coord<T>,
+    c: Coord<T>,
+    p: Coord<T>,
+) -> bool
+where
+    T: CoordFloat,
+{
+    // The same determinant as without the feature.
+    robust::incircle(exact(a), exact(b), exact(c), exact(p)) < 0_f64
+}
+
+// Converts to f64 for the exact predicates, without loss for f32 and f64.
+#[cfg(feature = "robust")]
+fn exact<T>(p: Coord<T>) -> robust::Coord<f64>
+where
+    T: CoordFloat,
+{
+    robust::Coord {
+        x: p.x.to_f64().unwrap_or(f64::NAN),
+        y: p.y.to_f64().unwrap_or(f64::NAN),
+    }
+}
+
+/// The center of the circle through a, b and c.
+pub(super) fn circumcenter<T>(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> Coord<T>
+where
+    T: CoordFloat,
+{
+    // As in Voronoi::init(), relative to a.
+    let (bx, by) = (b.x - a.x, b.y - a.y);
+    let (cx, cy) = (c.x - a.x, c.y - a.y);
+    let bl = bx * bx + by * by;
+    let cl = cx * cx + cy * cy;
+    // orient() is the negative of the usual cross product.
+    let d = -T::one() / (orient(a, b, c) * (T::one() + T::one()));
+    Coord {
+        x: a.x + (cy * bl - by * cl) * d,
+        y: a.y + (bx * cl - cx * bl) * d,
+    }
+}
+
+#[inline]
+pub(super) fn link(halfedges: &mut [usize], a: usize, b: usize) {
+    halfedges[a] = b;
+    if b != EMPTY {
+        halfedges[b] = a;
+    }
+}
+
+#[inline]
+pub(super) const fn key(u: usize, v: usize) -> (usize, usize) {
+    if u < v {
+        (u, v)
+    } else {
+        (v, u)
+    }
+}
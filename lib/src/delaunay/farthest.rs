@@ -0,0 +1,146 @@
+//! The farthest point from a query point, and the smallest enclosing circle.
+//!
+//! Both are found among the points of the hull, the farthest point from
+//! any point of the plane lies on the convex hull.
+
+use geo::CoordFloat;
+use geo_types::Coord;
+use num_traits::FloatConst;
+use num_traits::FromPrimitive;
+
+use super::constrain::circumcenter;
+use super::constrain::orient;
+use super::Delaunay;
+use crate::farthest::Circle;
+use crate::geometry::dist2;
+
+impl<T> Delaunay<T>
+where
+    T: CoordFloat + FloatConst + FromPrimitive,
+{
+    /// Returns the index of the point farthest from p.
+    ///
+    /// The cost is linear in the number of points on the hull. Of several
+    /// points at the same distance, the first on the hull is returned.
+    ///
+    /// None when there are no points, or p is not finite.
+    pub fn farthest(&self, p: &Coord<T>) -> Option<usize> {
+        if !(p.x.is_finite() && p.y.is_finite()) {
+            return None;
+        }
+        self.hull()
+            .fold(None, |farthest: Option<(usize, T)>, i| {
+                let d = dist2(&self.points[i], p);
+                match farthest {
+                    Some((_, max)) if max >= d => farthest,
+                    _ => Some((i, d)),
+                }
+            })
+            .map(|(i, _)| i)
+    }
+
+    /// Returns the smallest circle enclosing all the points.
+    ///
+    /// Welzl, "Smallest enclosing disks (balls and ellipsoids)", 1991. The
+    /// points of the hull are visited in a fixed pseudo-random order, so the
+    /// expected cost is linear in their number.
+    ///
+    /// None when there are no points.
+    #[must_use]
+    pub fn enclosing_circle(&self) -> Option<Circle<T>> {
+        let mut hull: Vec<Coord<T>> =
+            self.hull().map(|i| self.points[i]).collect();
+        shuffle(&mut hull);
+
+        let mut circle = Circle {
+            center: *hull.first()?,
+            radius: T::zero(),
+        };
+        for i in 1..hull.len() {
+            if encloses(&circle, &hull[i]) {
+                continue;
+            }
+            circle = Circle {
+                center: hull[i],
+                radius: T::zero(),
+            };
+            for j in 0..i {
+                if encloses(&circle, &hull[j]) {
+                    continue;
+                }
+                circle = diameter(&hull[i], &hull[j]);
+                for k in 0..j {
+                    if !encloses(&circle, &hull[k]) {
+                        circle = circumcircle(&hull[i], &hull[j], &hull[k]);
+                    }
+                }
+            }
+        }
+        Some(circle)
+    }
+}
+
+// True if p lies within the circle, allowing for rounding.
+fn encloses<T>(circle: &Circle<T>, p: &Coord<T>) -> bool
+where
+    T: CoordFloat,
+{
+    let tolerance = T::one() + T::epsilon() * T::from(64_f64).unwrap();
+    dist2(&circle.center, p).sqrt() <= circle.radius * tolerance
+}
+
+// The circle with a and b at either end of a diameter.
+fn diameter<T>(a: &Coord<T>, b: &Coord<T>) -> Circle<T>
+where
+    T: CoordFloat,
+{
+    let two = T::one() + T::one();
+    Circle {
+        center: (*a + *b) / two,
+        radius: dist2(a, b).sqrt() / two,
+    }
+}
+
+// The circle through a, b and c, or when they are collinear the circle
+// across the two furthest apart.
+fn circumcircle<T>(a: &Coord<T>, b: &Coord<T>, c: &Coord<T>) -> Circle<T>
+where
+    T: CoordFloat,
+{
+    if orient(*a, *b, *c).is_zero() {
+        let pairs = [(a, b), (b, c), (c, a)];
+        let (p, q) = pairs
+            .into_iter()
+            .reduce(|max, pair| {
+                if dist2(pair.0, pair.1) > dist2(max.0, max.1) {
+                    pair
+                } else {
+                    max
+                }
+            })
+            .unwrap_or((a, b));
+        return diameter(p, q);
+    }
+    let center = circumcenter(*a, *b, *c);
+    // The furthest of the three absorbs the rounding of the center.
+    let radius = [a, b, c]
+        .into_iter()
+        .map(|p| dist2(&center, p))
+        .fold(T::zero(), T::max)
+        .sqrt();
+    Circle { center, radius }
+}
+
+// A Fisher-Yates shuffle driven by a linear congruential generator, with a
+// fixed seed so the result does not vary from run to run.
+fn shuffle<T>(points: &mut [T]) {
+    let mut state: u64 = 0x853c_49e6_748f_ea9b;
+    for i in (1..points.len()).rev() {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        // The high bits are the most random.
+        let j = usize::try_from((state >> 33) % (i as u64 + 1)).unwrap_or(0);
+        points.swap(i, j);
+    }
+}
@@ -1,16 +1,38 @@
 #![allow(clippy::many_single_char_names)]
 //! Holds helper functions and a wrapper struct that hold data associated with a delaunay triangulation.
 //!
+//! Every method of [`Delaunay`] lives in this module or one of its
+//! submodules, where the halfedges of the triangulation can be reached.
+//! The public types those methods take or return live in a top level
+//! module named after the feature, so [`Delaunay::clusters`] is
+//! implemented in `delaunay/clusters.rs` and returns a
+//! [`Clusters`](crate::clusters::Clusters) from `clusters.rs`.
 
+mod alpha;
+mod carve;
+mod clusters;
 mod colinear;
+mod constrain;
+mod farthest;
 mod jitter;
+mod natural;
+mod nearest;
+mod neighbors;
+mod raster;
+mod refine;
 
 use core::cmp::Ordering;
 use core::fmt::Debug;
 use core::fmt::Display;
 
+use carve::carve;
 use colinear::colinear;
 use colinear::Tri;
+use constrain::constrain;
+#[cfg(feature = "robust")]
+use constrain::legalize;
+pub(crate) use constrain::orient;
+use delaunator::next_halfedge;
 use delaunator::triangulate;
 use delaunator::Point as DPoint;
 use delaunator::Triangulation;
@@ -23,12 +45,20 @@ use generator::Generator;
 use generator::Gn;
 use geo::CoordFloat;
 use geo_types::Coord;
+use geo_types::LineString;
+use geo_types::Triangle;
 use jitter::jitter;
+use neighbors::Neighbors;
 use num_traits::float::FloatConst;
 use num_traits::FromPrimitive;
+use refine::refine;
 
+use crate::error::DelaunayError;
+use crate::farthest::FarthestVoronoi;
+use crate::geometry::dist2;
 use crate::path::Path;
 use crate::polygon::Polygon;
+use crate::quality::Quality;
 use crate::voronoi::Bounds;
 use crate::voronoi::Voronoi;
 use crate::CanvasRenderingContext2d;
@@ -51,7 +81,20 @@ where
     /// for other points, the choice of incoming halfedge is arbitrary.
     /// The inedges table can be used to traverse the Delaunay triangulation
     pub inedges: Vec<usize>,
-    hull_index: Vec<usize>,
+    /// When all the points are collinear, the point indexes sorted along the line.
+    ///
+    /// In that case the triangulation is computed from slightly jittered
+    /// copies of the points, `points` itself is left untouched.
+    pub collinear: Option<Vec<usize>>,
+    // The inverse of collinear: the position of each point in the ordering.
+    collinear_index: Vec<usize>,
+    // Pairs of point indexes that must be joined by an edge.
+    constraints: Vec<(usize, usize)>,
+    // For each halfedge, true if it lies on a constraint.
+    // Empty when there are no constraints.
+    constrained: Vec<bool>,
+    // When true, the triangles outside the constraint rings are removed.
+    polygon: bool,
     /// The coordinates of a point as an vector.
     pub points: Vec<Coord<T>>,
     // pub fx: FnTransform<T>,
@@ -66,12 +109,42 @@ where
         f.debug_tuple("Centroid<T>")
             .field(&self.delaunator)
             .field(&self.inedges)
-            .field(&self.hull_index)
+            .field(&self.collinear)
+            .field(&self.collinear_index)
+            .field(&self.constraints)
+            .field(&self.constrained)
+            .field(&self.polygon)
             .field(&self.points)
             .finish()
     }
 }
 
+/// Where a point lies in the triangulation, see [`Delaunay::locate`].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Location<T>
+where
+    T: CoordFloat,
+{
+    /// The point is inside the triangle, or on one of its edges.
+    Inside {
+        /// The index of the triangle, its points are
+        /// `triangles[3 * triangle..3 * triangle + 3]`.
+        triangle: usize,
+        /// The barycentric coordinates of the point, one for each point of
+        /// the triangle. They are non-negative and sum to one.
+        weights: [T; 3],
+    },
+    /// The point is outside the triangulation.
+    Outside {
+        /// The boundary halfedge nearest to the point.
+        edge: usize,
+        /// The weights of the start and end of the edge which give the
+        /// nearest point on it.
+        weights: [T; 2],
+    },
+}
+
 impl<T> Delaunay<T>
 where
     T: CoordFloat + FloatConst + FromPrimitive,
@@ -81,33 +154,135 @@ where
     ///
     /// Computes a delaunay triangulation and stores the results.
     pub fn new(points: &[Coord<T>]) -> Self {
-        // conversion into delaunay point!!!
-        let d_point_in: Vec<DPoint> = points
-            .iter()
-            .map(|p| DPoint {
-                x: p.x.to_f64().unwrap(),
-                y: p.y.to_f64().unwrap(),
-            })
-            .collect();
-
         // TODO breaking API change if all points are colinear
         // now returning a special triangulation where
         // all point are on the hull... I am not sure about the
         // implications of this yet.?????
-        let delaunator = triangulate(&d_point_in);
+        let delaunator = triangulate(&to_delaunator_points(points));
+        Self::from_triangulation(points, delaunator, Vec::new(), false)
+            .expect("Without constraints init() cannot fail.")
+    }
+
+    /// Computes a delaunay triangulation, rejecting input that would
+    /// otherwise produce a meaningless triangulation.
+    ///
+    /// # Errors
+    /// - [`DelaunayError::Conversion`] if a coordinate cannot be converted into f64.
+    /// - [`DelaunayError::NonFiniteCoordinate`] if a coordinate is NaN or infinite.
+    /// - [`DelaunayError::TooFewDistinctPoints`] if there are fewer than three distinct points.
+    pub fn try_new(points: &[Coord<T>]) -> Result<Self, DelaunayError> {
+        let delaunator = triangulate(&try_to_delaunator_points(points)?);
+        let out =
+            Self::from_triangulation(points, delaunator, Vec::new(), false)?;
+
+        let distinct = out.inedges.iter().filter(|e| **e != EMPTY).count();
+        if distinct < 3 {
+            return Err(DelaunayError::TooFewDistinctPoints(distinct));
+        }
+
+        Ok(out)
+    }
 
+    /// Computes a constrained delaunay triangulation, where each pair of
+    /// point indexes in edges is joined by an edge.
+    ///
+    /// A constraint passing through other points is split at those points.
+    /// The triangulation is delaunay except where a constraint intervenes,
+    /// so [`Delaunay::find`] may stop short of the nearest point when a
+    /// constraint lies in between.
+    ///
+    /// # Errors
+    /// - [`DelaunayError::Conversion`] if a coordinate cannot be converted into f64.
+    /// - [`DelaunayError::NonFiniteCoordinate`] if a coordinate is NaN or infinite.
+    /// - [`DelaunayError::InvalidConstraint`] if a constraint refers to a
+    ///   missing or coincident point, or joins a point to itself.
+    /// - [`DelaunayError::IntersectingConstraint`] if a constraint crosses an
+    ///   earlier constraint.
+    /// - [`DelaunayError::UnresolvedConstraint`] if rounding prevents a
+    ///   constraint from being inserted.
+    pub fn with_constraints(
+        points: &[Coord<T>],
+        edges: &[(usize, usize)],
+    ) -> Result<Self, DelaunayError> {
+        let delaunator = triangulate(&try_to_delaunator_points(points)?);
+        Self::from_triangulation(points, delaunator, edges.to_vec(), false)
+    }
+
+    /// Triangulates the interior of a polygon, which may have holes.
+    ///
+    /// The points are the vertices of the exterior ring followed by those of
+    /// each interior ring, without the closing vertex of each ring. The ring
+    /// segments are constraints, and the triangles outside the exterior ring
+    /// or inside a hole are removed, so `hull` holds the exterior ring.
+    ///
+    /// # Errors
+    /// - [`DelaunayError::Conversion`] if a coordinate cannot be converted into f64.
+    /// - [`DelaunayError::NonFiniteCoordinate`] if a coordinate is NaN or infinite.
+    /// - [`DelaunayError::InvalidConstraint`] if a ring repeats a vertex.
+    /// - [`DelaunayError::IntersectingConstraint`] if the rings cross.
+    /// - [`DelaunayError::UnresolvedConstraint`] if rounding prevents an edge
+    ///   of a ring from being inserted.
+    pub fn from_polygon(
+        polygon: &geo_types::Polygon<T>,
+    ) -> Result<Self, DelaunayError> {
+        let mut points = Vec::new();
+        let mut constraints = Vec::new();
+        for ring in
+            core::iter::once(polygon.exterior()).chain(polygon.interiors())
+        {
+            let mut coords = &ring.0[..];
+            if ring.is_closed() {
+                coords = &coords[..coords.len().saturating_sub(1)];
+            }
+            let first = points.len();
+            points.extend_from_slice(coords);
+            let last = points.len();
+            if last - first > 1 {
+                constraints.extend(
+                    (first..last).map(|i| {
+                        (i, if i + 1 == last { first } else { i + 1 })
+                    }),
+                );
+            }
+        }
+        let delaunator = triangulate(&try_to_delaunator_points(&points)?);
+        Self::from_triangulation(&points, delaunator, constraints, true)
+    }
+
+    fn from_triangulation(
+        points: &[Coord<T>],
+        delaunator: Triangulation,
+        constraints: Vec<(usize, usize)>,
+        polygon: bool,
+    ) -> Result<Self, DelaunayError> {
         let mut out = Self {
             delaunator,
-            inedges: Vec::with_capacity(points.len() / 2),
-            hull_index: Vec::with_capacity(points.len() / 2),
+            inedges: Vec::with_capacity(points.len()),
+            collinear: None,
+            collinear_index: Vec::new(),
+            constraints,
+            constrained: Vec::new(),
+            polygon,
             points: points.to_vec(),
             // fx: Box::new(|p: Point<T>, _i: usize, _points: Vec<Point<T>>| p.x()),
             // fy: Box::new(|p: Point<T>, _i: usize, _points: Vec<Point<T>>| p.y()),
         };
 
-        out.init();
+        out.init()?;
 
-        out
+        Ok(out)
+    }
+
+    /// The pairs of point indexes that must be joined by an edge.
+    #[must_use]
+    pub fn constraints(&self) -> &[(usize, usize)] {
+        &self.constraints
+    }
+
+    /// Returns true if halfedge e lies on a constraint.
+    #[must_use]
+    pub fn is_constrained(&self, e: usize) -> bool {
+        self.constrained.get(e).copied().unwrap_or(false)
     }
 
     #[inline]
@@ -116,17 +291,125 @@ where
         Voronoi::new(self, bounds)
     }
 
-    // pub fn update(&mut self) {
-    //     todo!("how to enulate calls to delaunator::update()");
-    //     self.init();
-    // }
+    #[inline]
+    /// Use the stored delaunay mesh data to compute the associated voronoi mesh.
+    ///
+    /// # Errors
+    /// [`DelaunayError::InvalidBounds`] if the bounds are reversed or not finite.
+    pub fn try_voronoi(
+        self,
+        bounds: Option<Bounds<T>>,
+    ) -> Result<Voronoi<T>, DelaunayError> {
+        Voronoi::try_new(self, bounds)
+    }
+
+    #[inline]
+    /// Computes the farthest-point voronoi diagram of the points.
+    ///
+    /// # Panics
+    ///  When the bounds are invalid, see [`FarthestVoronoi::try_new`].
+    pub fn farthest_voronoi(
+        self,
+        bounds: Option<Bounds<T>>,
+    ) -> FarthestVoronoi<T> {
+        FarthestVoronoi::new(self, bounds)
+    }
+
+    #[inline]
+    /// Computes the farthest-point voronoi diagram of the points.
+    ///
+    /// # Errors
+    /// [`DelaunayError::InvalidBounds`] if the bounds are reversed or not finite.
+    pub fn try_farthest_voronoi(
+        self,
+        bounds: Option<Bounds<T>>,
+    ) -> Result<FarthestVoronoi<T>, DelaunayError> {
+        FarthestVoronoi::try_new(self, bounds)
+    }
+
+    /// Adds points until every triangle meets the quality targets,
+    /// returning the number of points added.
+    ///
+    /// The new points are appended to `points`, placed at the circumcenter
+    /// of a bad triangle or at the midpoint of a constraint or hull edge.
+    /// The boundary is respected, so after [`Delaunay::from_polygon`] no
+    /// point is placed outside the polygon or inside a hole. A constraint
+    /// which is split is replaced by its pieces in [`Delaunay::constraints`].
+    ///
+    /// Does nothing when there are fewer than three points, or all the
+    /// points are collinear.
+    ///
+    /// # Errors
+    /// [`DelaunayError::UnresolvedConstraint`] if rounding prevents a piece
+    /// of a constraint from being inserted again. The points are kept and
+    /// the triangulation is complete, without that piece.
+    pub fn refine(
+        &mut self,
+        quality: &Quality<T>,
+    ) -> Result<usize, DelaunayError> {
+        if self.collinear.is_some() {
+            return Ok(0);
+        }
+        let added = refine(
+            &mut self.delaunator,
+            &self.constrained,
+            &mut self.points,
+            &mut self.constraints,
+            quality,
+        );
+        if added > 0 {
+            self.init()?;
+        }
+        Ok(added)
+    }
+
+    /// Recomputes the triangulation after `points` has been modified in place.
+    ///
+    /// The `inedges` buffer is reused, so this is cheaper
+    /// than constructing a new object when animating a fixed set of points.
+    ///
+    /// delaunator-rs has no equivalent of `Delaunator.update()`, so the
+    /// triangulation itself is recomputed.
+    ///
+    /// The constraints are inserted again.
+    ///
+    /// # Panics
+    ///  When a constraint can no longer be inserted, see
+    ///  [`Delaunay::try_update`].
+    pub fn update(&mut self) -> &mut Self {
+        self.try_update()
+            .expect("A constraint could not be inserted again.")
+    }
+
+    /// Recomputes the triangulation after `points` has been modified in place.
+    ///
+    /// As [`Delaunay::update`], reporting a constraint which can no longer
+    /// be inserted. The triangulation is still complete, without that
+    /// constraint.
+    ///
+    /// # Errors
+    /// - [`DelaunayError::InvalidConstraint`] if a constraint now joins
+    ///   coincident points.
+    /// - [`DelaunayError::IntersectingConstraint`] if a constraint now
+    ///   crosses an earlier constraint.
+    /// - [`DelaunayError::UnresolvedConstraint`] if rounding prevents a
+    ///   constraint from being inserted.
+    pub fn try_update(&mut self) -> Result<&mut Self, DelaunayError> {
+        self.delaunator = triangulate(&to_delaunator_points(&self.points));
+        self.init()?;
+        Ok(self)
+    }
 
-    fn init(&mut self) {
+    fn init(&mut self) -> Result<(), DelaunayError> {
+        let mut jittered_points = None;
         // Check for colinear.
         if self.delaunator.hull.len() > 2usize
             && colinear(&self.points, &self.delaunator) == Tri::Collinear
         {
-            let mut colinear_vec: Vec<usize> = (0..self.points.len()).collect();
+            let mut colinear_vec = self.collinear.take().unwrap_or_default();
+            colinear_vec.clear();
+            colinear_vec.extend(0..self.points.len());
+            // for exact neighbors
             colinear_vec.sort_by(|i, j| {
                 let x_diff = self.points[*i].x - self.points[*j].x;
                 if x_diff == T::zero() {
@@ -153,45 +436,48 @@ where
                 self.points[f].y,
             ];
             let r = T::from_f64(1e-8).unwrap()
-                * (bounds[3] - bounds[1]).hypot(bounds[3] - bounds[0]);
-            for i in 0..self.points.len() {
-                let p = jitter(&self.points[i], r);
-                self.points[i].x = p.x;
-                self.points[i].y = p.y;
+                * (bounds[3] - bounds[1]).hypot(bounds[2] - bounds[0]);
+
+            // Deviation from JS, the points are not modified in place.
+            // The jittered copy is only used to compute the triangulation.
+            let jittered: Vec<Coord<T>> =
+                self.points.iter().map(|p| jitter(p, r)).collect();
+            self.delaunator = triangulate(&to_delaunator_points(&jittered));
+            jittered_points = Some(jittered);
+
+            self.collinear_index.clear();
+            self.collinear_index.resize(self.points.len(), EMPTY);
+            for (l, i) in colinear_vec.iter().enumerate() {
+                self.collinear_index[*i] = l;
             }
-            let d_point_in: Vec<DPoint> = self
-                .points
-                .iter()
-                .map(|p| DPoint {
-                    x: p.x.to_f64().unwrap(),
-                    y: p.y.to_f64().unwrap(),
-                })
-                .collect();
-
-            // TODO breaking API change if all points are collinear
-            // now returning a special triangulation where
-            // all point are on the hull... I am not sure about the
-            // implications of this yet.?????
-            self.delaunator = triangulate(&d_point_in);
-            // self.delaunator = match triangulate(&d_point_in) {
-            //     Some(d) => d,
-            //     None => {
-            //         // When triangulation fails the javascript response
-            //         // is mostly empty but hull has an value.
-            //         Triangulation {
-            //             triangles: Vec::new(),
-            //             halfedges: Vec::new(),
-            //             hull: (0..d_point_in.len()).collect(),
-            //         }
-            //     }
-            // };
+            self.collinear = Some(colinear_vec);
+        } else {
+            self.collinear = None;
+            self.collinear_index.clear();
         }
 
+        // The jittered triangulation of collinear points has no meaning.
+        #[cfg(feature = "robust")]
+        if self.collinear.is_none() {
+            legalize(&mut self.delaunator, &self.points);
+        }
+
+        // Edges are flipped, so this must precede the inedges computation.
+        let constrained = constrain(
+            &mut self.delaunator,
+            &mut self.constrained,
+            &self.constraints,
+            jittered_points.as_deref().unwrap_or(&self.points),
+        );
+        // The rings of a polygon start with the exterior, at point 0.
+        if self.polygon && self.collinear.is_none() {
+            carve(&mut self.delaunator, &mut self.constrained, 0);
+        }
+
+        // Reuse the existing allocations, when called from update().
         let len = self.points.len();
-        self.inedges = Vec::with_capacity(len);
-        self.hull_index = Vec::with_capacity(len);
-        self.inedges = vec![EMPTY; len];
-        self.hull_index = vec![EMPTY; len];
+        self.inedges.clear();
+        self.inedges.resize(len, EMPTY);
 
         // Compute an index from each point to an (arbitrary) incoming halfedge
         // Used to give the first neighbor of each point; for this reason,
@@ -207,10 +493,6 @@ where
             }
         }
 
-        for (i, h) in self.delaunator.hull.iter().enumerate() {
-            self.hull_index[*h] = i;
-        }
-
         // degenerate case: 1 or 2 (distinct) points
         if self.delaunator.hull.len() <= 2 && !self.delaunator.hull.is_empty() {
             self.delaunator.triangles = vec![EMPTY, EMPTY, EMPTY];
@@ -223,52 +505,17 @@ where
                 self.delaunator.triangles[2] = self.delaunator.hull[1];
             }
         }
+
+        constrained
     }
 
-    // Returns a generator that returns the neighbors for a given point
-    // specified at the time of generation.
-    // fn neighbors_generator(&self, i: usize) -> Generator<'_, (), Polygon<T>> {
-    //     Gn::new_scoped(move |s| {
-    //         // degenerate case with several collinear points
-    //         if self.collinear.is_empty() {
-    //             let l = self.collinear[i];
-    //             if l > 0 {
-    //                 s.yield_(Some(self.collinear[l - 1]));
-    //             }
-    //             if l < self.collinear.len() - 1 {
-    //                 s.yield_(Some(self.collinear[l + 1]));
-    //             }
-    //             done!();
-    //         }
-
-    //         let e0 = self.inedges[i];
-    //         if e0 == EMPTY {
-    //             done!()
-    //         }; // coincident point
-    //         let e = e0;
-    //         let p0 = EMPTY;
-    //         loop {
-    //             p0 = self.triangles[e];
-    //             s.yield_(Some(p0));
-    //             // e = e % 3 == 2 ? e - 2 : e + 1;
-    //             let e = if e % 3 == 2 { e - 2 } else { e + 1 };
-    //             if self.triangles[e] != i {
-    //                 done!()
-    //             }; // bad triangulation
-    //             e = self.half_edges[e];
-    //             if e == EMPTY {
-    //                 let p = self.delaunator.hull[(self.hull_index[i] + 1) % self.hull.len()];
-    //                 if p != p0 {
-    //                     s.yield_(Some(p));
-    //                 }
-    //                 done!();
-    //             }
-    //             if e == e0 {
-    //                 break None;
-    //             }
-    //         }
-    //     })
-    // }
+    /// Returns an iterator over the indexes of the points that share an
+    /// edge with point i in the triangulation.
+    ///
+    /// Yields nothing for a coincident point.
+    pub fn neighbors(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        Neighbors::new(self, i)
+    }
 
     /// Returns the index of the point that is closest to the specified point p.
     /// The search is started at the specified point i.
@@ -290,8 +537,14 @@ where
     /// Step through the triangulation, starting at i, return the index
     /// of the point closets to point p.
     pub fn step(&self, i: usize, p: &Coord<T>) -> usize {
-        if self.inedges[i] == EMPTY || self.points.is_empty() {
-            return (i + 1) % (self.points.len() >> 1);
+        if self.points.is_empty() {
+            return EMPTY;
+        }
+        if self.inedges[i] == EMPTY {
+            return (i + 1) % self.points.len();
+        }
+        if self.collinear.is_some() {
+            return self.step_collinear(i, p);
         }
         let mut c = i;
         let dx = p.x - self.points[i].x;
@@ -301,12 +554,16 @@ where
         let mut e = e0;
         loop {
             let t = self.delaunator.triangles[e];
-            let dx = p.x - self.points[t].x;
-            let dy = p.y - self.points[t].y;
-            let dt = dx * dx + dy * dy;
-            if dt < dc {
-                dc = dt;
-                c = t;
+            // In the degenerate case of 1 distinct point the triangle is
+            // padded with EMPTY. ( JS compares against NaN here. )
+            if t != EMPTY {
+                let dx = p.x - self.points[t].x;
+                let dy = p.y - self.points[t].y;
+                let dt = dx * dx + dy * dy;
+                if dt < dc {
+                    dc = dt;
+                    c = t;
+                }
             }
 
             if e % 3 == 2 {
@@ -319,10 +576,13 @@ where
                 // bad triangulation
                 break;
             }
+            let out = e;
             e = self.delaunator.halfedges[e];
             if e == EMPTY {
-                e = self.delaunator.hull
-                    [(self.hull_index[i] + 1) % self.delaunator.hull.len()];
+                // Deviation from JS: the boundary is not always the
+                // convex hull, so look up the next boundary point from
+                // the outgoing halfedge rather than the hull.
+                e = self.delaunator.triangles[next_halfedge(out)];
                 let dx = p.x - self.points[e].x;
                 let dy = p.y - self.points[e].y;
                 if e != t && dx * dx + dy * dy < dc {
@@ -338,6 +598,189 @@ where
         c
     }
 
+    /// Returns the triangle containing point p, with the barycentric
+    /// coordinates of p, or the nearest boundary edge when p is outside.
+    ///
+    /// The walk starts from the triangle hint, typically the result of a
+    /// previous call for a nearby point. If the hint is not specified, it
+    /// starts at zero.
+    ///
+    /// Returns None when there are no triangles, all the points are
+    /// collinear, or p is not finite.
+    pub fn locate(
+        &self,
+        p: &Coord<T>,
+        hint: Option<usize>,
+    ) -> Option<Location<T>> {
+        let n = self.delaunator.triangles.len() / 3;
+        if n == 0
+            || self.delaunator.hull.len() < 3
+            || self.collinear.is_some()
+            || !(p.x.is_finite() && p.y.is_finite())
+        {
+            return None;
+        }
+
+        let mut t = hint.filter(|t| *t < n).unwrap_or(0);
+        for _ in 0..n {
+            let Some(e) = self.exit(t, p) else {
+                return Some(self.inside(t, p));
+            };
+            let o = self.delaunator.halfedges[e];
+            if o == EMPTY {
+                if self.polygon {
+                    // The polygon may continue beyond a bay or a hole.
+                    break;
+                }
+                return Some(self.outside(p));
+            }
+            t = o / 3;
+        }
+
+        // Around constraints the walk can go in circles.
+        Some(
+            (0..n)
+                .find(|t| self.exit(*t, p).is_none())
+                .map_or_else(|| self.outside(p), |t| self.inside(t, p)),
+        )
+    }
+
+    /// Returns the value at p, linearly interpolated from the values at the
+    /// points of the triangle containing p.
+    ///
+    /// Returns None when p is outside the triangulation, see
+    /// [`Delaunay::locate`].
+    ///
+    /// # Panics
+    ///  When values holds fewer values than there are points.
+    pub fn interpolate_linear(&self, values: &[T], p: &Coord<T>) -> Option<T> {
+        self.interpolate_linear_with_hint(values, p, &mut None)
+    }
+
+    /// Samples [`Delaunay::interpolate_linear`] over a grid of width *
+    /// height cells covering the bounds, in row major order.
+    ///
+    /// Each cell is sampled at its center.
+    ///
+    /// # Panics
+    ///  When values holds fewer values than there are points.
+    pub fn interpolate_linear_grid(
+        &self,
+        values: &[T],
+        bounds: &Bounds<T>,
+        width: usize,
+        height: usize,
+    ) -> Vec<Option<T>> {
+        let mut hint = None;
+        grid(bounds, width, height)
+            .map(|p| self.interpolate_linear_with_hint(values, &p, &mut hint))
+            .collect()
+    }
+
+    // The hint is updated with the triangle containing p.
+    fn interpolate_linear_with_hint(
+        &self,
+        values: &[T],
+        p: &Coord<T>,
+        hint: &mut Option<usize>,
+    ) -> Option<T> {
+        assert!(
+            values.len() >= self.points.len(),
+            "There must be a value for each point."
+        );
+        match self.locate(p, *hint)? {
+            Location::Inside { triangle, weights } => {
+                *hint = Some(triangle);
+                let triangles = &self.delaunator.triangles;
+                Some((0..3).fold(T::zero(), |v, i| {
+                    v + values[triangles[3 * triangle + i]] * weights[i]
+                }))
+            }
+            Location::Outside { .. } => None,
+        }
+    }
+
+    // The first halfedge of triangle t with p strictly on the far side.
+    fn exit(&self, t: usize, p: &Coord<T>) -> Option<usize> {
+        let triangles = &self.delaunator.triangles;
+        (3 * t..3 * t + 3).find(|e| {
+            let a = self.points[triangles[*e]];
+            let b = self.points[triangles[next_halfedge(*e)]];
+            orient(a, b, *p) < T::zero()
+        })
+    }
+
+    fn inside(&self, t: usize, p: &Coord<T>) -> Location<T> {
+        let triangles = &self.delaunator.triangles;
+        let [a, b, c] = [0, 1, 2].map(|i| self.points[triangles[3 * t + i]]);
+        let area = orient(a, b, c);
+        Location::Inside {
+            triangle: t,
+            weights: [
+                orient(b, c, *p) / area,
+                orient(c, a, *p) / area,
+                orient(a, b, *p) / area,
+            ],
+        }
+    }
+
+    fn outside(&self, p: &Coord<T>) -> Location<T> {
+        let triangles = &self.delaunator.triangles;
+        let mut nearest = (T::infinity(), EMPTY, T::zero());
+        for (e, o) in self.delaunator.halfedges.iter().enumerate() {
+            if *o != EMPTY {
+                continue;
+            }
+            let a = self.points[triangles[e]];
+            let b = self.points[triangles[next_halfedge(e)]];
+            let ab = b - a;
+            let len2 = ab.x * ab.x + ab.y * ab.y;
+            let s = if len2.is_zero() {
+                T::zero()
+            } else {
+                (((p.x - a.x) * ab.x + (p.y - a.y) * ab.y) / len2)
+                    .max(T::zero())
+                    .min(T::one())
+            };
+            let d = dist2(p, &(a + ab * s));
+            if d < nearest.0 {
+                nearest = (d, e, s);
+            }
+        }
+        Location::Outside {
+            edge: nearest.1,
+            weights: [T::one() - nearest.2, nearest.2],
+        }
+    }
+
+    // Walks along the line, in the degenerate case where all points are collinear.
+    fn step_collinear(&self, i: usize, p: &Coord<T>) -> usize {
+        let mut c = i;
+        let mut dc = dist2(p, &self.points[i]);
+        for j in self.collinear_neighbors(i).into_iter().flatten() {
+            let dj = dist2(p, &self.points[j]);
+            if dj < dc {
+                dc = dj;
+                c = j;
+            }
+        }
+        c
+    }
+
+    /// When all points are collinear, returns the nearest points either side
+    /// of point i along the line, skipping over coincident points.
+    pub(crate) fn collinear_neighbors(&self, i: usize) -> [Option<usize>; 2] {
+        let Some(collinear) = &self.collinear else {
+            return [None, None];
+        };
+        let l = self.collinear_index[i];
+        let distinct = |j: &&usize| self.inedges[**j] != EMPTY;
+        [
+            collinear[..l].iter().rev().find(distinct).copied(),
+            collinear[l + 1..].iter().find(distinct).copied(),
+        ]
+    }
+
     /// Returns the delaunay mesh as a string.
     #[must_use]
     pub fn render_to_string(&self) -> String
@@ -419,24 +862,144 @@ where
     }
 
     /// Dumps the hull to the [`CanvasRenderingContext2d`].
+    ///
+    /// Nothing is drawn when there are no points.
     pub fn render_hull(&self, context: &mut impl CanvasRenderingContext2d<T>) {
-        let h = self.delaunator.hull[0];
-        let n = self.delaunator.hull.len();
-        context.move_to(&self.points[h]);
-        for i in 1..n {
-            let h = self.delaunator.hull[i];
-            context.line_to(&self.points[h]);
+        let mut hull = self.hull();
+        if let Some(h) = hull.next() {
+            context.move_to(&self.points[h]);
+            for h in hull {
+                context.line_to(&self.points[h]);
+            }
+            context.close_path();
         }
-        context.close_path();
     }
 
-    /// Returns the hull as series of [`Coord`]'s
+    /// Returns an iterator over the indices of the points on the hull,
+    /// in counterclockwise order.
+    ///
+    /// Empty when there are no points. A single index when all the points
+    /// are coincident.
+    pub fn hull(&self) -> impl Iterator<Item = usize> + '_ {
+        self.delaunator.hull.iter().copied()
+    }
+
+    /// Returns the hull as a closed [`LineString`].
+    ///
+    /// Empty when there are no points. In the degenerate cases of 1 or 2
+    /// distinct points the ring is [p0, p0] or [p0, p1, p0].
     #[must_use]
-    pub fn hull_polygon(&self) -> Vec<Coord<T>> {
+    pub fn hull_line_string(&self) -> LineString<T> {
         let mut polygon = Polygon::default();
         self.render_hull(&mut polygon);
-        polygon.0
+        LineString::new(polygon.0)
+    }
+
+    /// Returns the hull as a [`geo_types::Polygon`] without interiors.
+    ///
+    /// see [`Delaunay::hull_line_string`] for the degenerate cases.
+    #[must_use]
+    pub fn hull_polygon(&self) -> geo_types::Polygon<T> {
+        geo_types::Polygon::new(self.hull_line_string(), vec![])
+    }
+
+    /// Returns the selected triangle.
+    ///
+    /// The vertices are in the same order as `triangle_polygon()`.
+    #[must_use]
+    pub fn triangle(&self, i: usize) -> Triangle<T> {
+        let t = &self.delaunator.triangles[i * 3..i * 3 + 3];
+        Triangle::unchecked_winding(
+            self.points[t[0]],
+            self.points[t[1]],
+            self.points[t[2]],
+        )
+    }
+
+    /// Returns an iterator over the triangles.
+    pub fn triangles(&self) -> impl Iterator<Item = Triangle<T>> + '_ {
+        self.triangle_indices().map(|i| self.triangle(i))
+    }
+
+    /// Returns an iterator over the triangles, as closed polygons.
+    ///
+    /// Deviation from javascript: In the degenerate case of 1 distinct point
+    /// there are no triangles, rather than a triangle of undefined points.
+    pub fn triangle_polygons(
+        &self,
+    ) -> impl Iterator<Item = geo_types::Polygon<T>> + '_ {
+        self.triangle_indices()
+            .map(|i| self.triangle(i).to_polygon())
     }
+
+    // Skips the padding used in the degenerate case of 1 distinct point.
+    fn triangle_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.delaunator
+            .triangles
+            .chunks_exact(3)
+            .enumerate()
+            .filter(|(_, t)| !t.contains(&EMPTY))
+            .map(|(i, _)| i)
+    }
+}
+
+#[inline]
+/// The centers of width * height cells covering the bounds, in row major
+/// order.
+pub(crate) fn grid<T>(
+    bounds: &Bounds<T>,
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = Coord<T>> + '_
+where
+    T: CoordFloat + FromPrimitive,
+{
+    let half = T::one() / (T::one() + T::one());
+    let dx = bounds.width() / T::from_usize(width.max(1)).unwrap();
+    let dy = bounds.height() / T::from_usize(height.max(1)).unwrap();
+    (0..height).flat_map(move |j| {
+        (0..width).map(move |i| Coord {
+            x: bounds.xmin + (T::from_usize(i).unwrap() + half) * dx,
+            y: bounds.ymin + (T::from_usize(j).unwrap() + half) * dy,
+        })
+    })
+}
+
+/// Conversion into delaunator's point type.
+///
+/// # Panics
+/// `unwrap()` is used here but a panic will never happen as T will always be converted into f64.
+fn to_delaunator_points<T>(points: &[Coord<T>]) -> Vec<DPoint>
+where
+    T: CoordFloat,
+{
+    points
+        .iter()
+        .map(|p| DPoint {
+            x: p.x.to_f64().unwrap(),
+            y: p.y.to_f64().unwrap(),
+        })
+        .collect()
+}
+
+/// Fallible conversion into delaunator's point type.
+fn try_to_delaunator_points<T>(
+    points: &[Coord<T>],
+) -> Result<Vec<DPoint>, DelaunayError>
+where
+    T: CoordFloat,
+{
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| match (p.x.to_f64(), p.y.to_f64()) {
+            (Some(x), Some(y)) if x.is_finite() && y.is_finite() => {
+                Ok(DPoint { x, y })
+            }
+            (Some(_), Some(_)) => Err(DelaunayError::NonFiniteCoordinate(i)),
+            _ => Err(DelaunayError::Conversion(i)),
+        })
+        .collect()
 }
 
 /// Generator and helper.
@@ -455,8 +1018,8 @@ where
         let t1 = self.delaunator.triangles[i + 1];
         let t2 = self.delaunator.triangles[i + 2];
         context.move_to(&self.points[t0]);
-        context.move_to(&self.points[t1]);
-        context.move_to(&self.points[t2]);
+        context.line_to(&self.points[t1]);
+        context.line_to(&self.points[t2]);
         context.close_path();
     }
 
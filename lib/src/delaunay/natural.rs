@@ -0,0 +1,132 @@
+//! Natural neighbor (Sibson) coordinates.
+//!
+//! Sibson, "A brief description of natural neighbour interpolation",
+//! Interpreting Multivariate Data (1981).
+//!
+//! Inserting the query point would replace the triangles whose
+//! circumcircle contains it, the Bowyer-Watson cavity. The points on the
+//! boundary of the cavity are the natural neighbors, and each gives up the
+//! part of its voronoi cell bounded by the circumcenters of the replaced
+//! triangles around it and the two new circumcenters either side.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use delaunator::next_halfedge;
+use delaunator::EMPTY;
+use geo::CoordFloat;
+use geo_types::Coord;
+use num_traits::FloatConst;
+use num_traits::FromPrimitive;
+
+use super::constrain::circumcenter;
+use super::constrain::in_circle;
+use super::Delaunay;
+use super::Location;
+use crate::geometry::signed_area;
+
+impl<T> Delaunay<T>
+where
+    T: CoordFloat + FloatConst + FromPrimitive,
+{
+    /// Returns each natural neighbor of p with its weight, the weights sum
+    /// to one.
+    ///
+    /// The circumcenters are those of the voronoi diagram. The hint is
+    /// used to start the search, and is updated with the triangle
+    /// containing p.
+    ///
+    /// None when p is outside the triangulation.
+    pub(crate) fn natural_neighbors(
+        &self,
+        circumcenters: &[Coord<T>],
+        p: &Coord<T>,
+        hint: &mut Option<usize>,
+    ) -> Option<Vec<(usize, T)>> {
+        let Location::Inside { triangle, weights } = self.locate(p, *hint)?
+        else {
+            return None;
+        };
+        *hint = Some(triangle);
+        let triangles = &self.delaunator.triangles;
+        let halfedges = &self.delaunator.halfedges;
+        let corner = |e: usize| self.points[triangles[e]];
+
+        if let Some(i) =
+            (3 * triangle..3 * triangle + 3).find(|e| corner(*e) == *p)
+        {
+            return Some(vec![(triangles[i], T::one())]);
+        }
+
+        // The set answers membership, the order of the walk is kept so the
+        // neighbors, and the sums over them, are the same on every run.
+        let mut cavity = HashSet::from([triangle]);
+        let mut order = vec![triangle];
+        let mut stack = vec![triangle];
+        while let Some(t) = stack.pop() {
+            for o in &halfedges[3 * t..3 * t + 3] {
+                let o = *o;
+                if o == EMPTY || cavity.contains(&(o / 3)) {
+                    continue;
+                }
+                let s = 3 * (o / 3);
+                if in_circle(corner(s), corner(s + 1), corner(s + 2), *p) {
+                    cavity.insert(o / 3);
+                    order.push(o / 3);
+                    stack.push(o / 3);
+                }
+            }
+        }
+
+        // The boundary halfedges of the cavity, by their end point.
+        let mut incoming = HashMap::new();
+        let mut outgoing = vec![];
+        for t in &order {
+            for e in 3 * t..3 * t + 3 {
+                let o = halfedges[e];
+                if o == EMPTY || !cavity.contains(&(o / 3)) {
+                    incoming.insert(triangles[next_halfedge(e)], e);
+                    outgoing.push(e);
+                }
+            }
+        }
+
+        let mut neighbors = Vec::with_capacity(outgoing.len());
+        let mut total = T::zero();
+        for e_out in outgoing {
+            let v = triangles[e_out];
+            let e_in = incoming[&v];
+            let mut cell = vec![circumcenter(*p, corner(e_in), self.points[v])];
+            // Turn around v, through the cavity, from e_in to e_out.
+            let mut f = next_halfedge(e_in);
+            cell.push(circumcenters[f / 3]);
+            while f != e_out {
+                f = next_halfedge(halfedges[f]);
+                cell.push(circumcenters[f / 3]);
+            }
+            cell.push(circumcenter(
+                *p,
+                self.points[v],
+                corner(next_halfedge(e_out)),
+            ));
+
+            let area = signed_area(&cell);
+            total = total + area;
+            neighbors.push((v, area));
+        }
+
+        // On the hull the coordinates are those along the edge.
+        if !total.is_finite() || total.is_zero() {
+            return Some(
+                (0..3)
+                    .filter(|i| !weights[*i].is_zero())
+                    .map(|i| (triangles[3 * triangle + i], weights[i]))
+                    .collect(),
+            );
+        }
+        for (_, w) in &mut neighbors {
+            *w = *w / total;
+        }
+        Some(neighbors)
+    }
+}
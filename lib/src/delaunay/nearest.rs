@@ -0,0 +1,162 @@
+//! The nearest points to a query point, found by walking out from the
+//! nearest point through the triangulation.
+//!
+//! Dickerson, Drysdale and Sack, "Simple algorithms for enumerating
+//! interpoint distances and finding k nearest neighbors", International
+//! Journal of Computational Geometry and Applications 2 (1992). The points
+//! inside any circle about the query point are joined by the edges of the
+//! delaunay triangulation, so visiting the neighbours of the points in
+//! order of distance yields every point in order of distance.
+
+use core::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use delaunator::EMPTY;
+use geo::CoordFloat;
+use geo_types::Coord;
+use num_traits::FloatConst;
+use num_traits::FromPrimitive;
+
+use super::Delaunay;
+use crate::geometry::dist2;
+
+impl<T> Delaunay<T>
+where
+    T: CoordFloat + FloatConst + FromPrimitive,
+{
+    /// Returns the indexes of the k points nearest to p, nearest first.
+    ///
+    /// The search starts from [`Delaunay::find`], begun at point i when
+    /// given, and its cost grows with k rather than the number of points.
+    /// Points at the same distance are in increasing order of index. A
+    /// point which coincides with another is left out, as it is by
+    /// [`Delaunay::find`].
+    ///
+    /// Fewer than k points are returned when there are not enough, and none
+    /// when p is not finite. With constraints, a constraint between p and a
+    /// point may hide that point.
+    pub fn k_nearest(
+        &self,
+        p: &Coord<T>,
+        k: usize,
+        i: Option<usize>,
+    ) -> Vec<usize> {
+        if k == 0 {
+            return vec![];
+        }
+        let mut nearest: Vec<(T, usize)> = vec![];
+        for (d, j) in self.walk(p, i) {
+            // Keep going past the k-th point for any at the same distance.
+            if nearest.len() >= k && d > nearest[k - 1].0 {
+                break;
+            }
+            nearest.push((d, j));
+        }
+        sort(&mut nearest);
+        nearest.truncate(k);
+        nearest.into_iter().map(|(_, j)| j).collect()
+    }
+
+    /// Returns the indexes of the points within distance r of p, nearest
+    /// first.
+    ///
+    /// The search starts from [`Delaunay::find`], begun at point i when
+    /// given, and its cost grows with the number of points returned. Points
+    /// at the same distance are in increasing order of index. A point which
+    /// coincides with another is left out, as it is by [`Delaunay::find`].
+    ///
+    /// None are returned when p is not finite, or r is negative. With
+    /// constraints, a constraint between p and a point may hide that point.
+    pub fn within_radius(
+        &self,
+        p: &Coord<T>,
+        r: T,
+        i: Option<usize>,
+    ) -> Vec<usize> {
+        if r.is_nan() || r < T::zero() {
+            return vec![];
+        }
+        let r2 = r * r;
+        let mut within: Vec<(T, usize)> =
+            self.walk(p, i).take_while(|(d, _)| *d <= r2).collect();
+        sort(&mut within);
+        within.into_iter().map(|(_, j)| j).collect()
+    }
+
+    // The points in order of their squared distance from p, by a best first
+    // search from the nearest point.
+    fn walk(
+        &self,
+        p: &Coord<T>,
+        i: Option<usize>,
+    ) -> impl Iterator<Item = (T, usize)> + '_ {
+        let p = *p;
+        let mut visited = vec![false; self.points.len()];
+        let mut heap = BinaryHeap::new();
+        if p.x.is_finite() && p.y.is_finite() && !self.points.is_empty() {
+            let start = self.find(&p, i.filter(|i| *i < self.points.len()));
+            if start != EMPTY {
+                visited[start] = true;
+                heap.push(Candidate(dist2(&self.points[start], &p), start));
+            }
+        }
+        core::iter::from_fn(move || {
+            let Candidate(d, j) = heap.pop()?;
+            for k in self.neighbors(j) {
+                if !visited[k] {
+                    visited[k] = true;
+                    heap.push(Candidate(dist2(&self.points[k], &p), k));
+                }
+            }
+            Some((d, j))
+        })
+    }
+}
+
+// An entry of the queue, the nearest point is the greatest.
+struct Candidate<T>(T, usize);
+
+impl<T> PartialEq for Candidate<T>
+where
+    T: CoordFloat,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Candidate<T> where T: CoordFloat {}
+
+impl<T> PartialOrd for Candidate<T>
+where
+    T: CoordFloat,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Candidate<T>
+where
+    T: CoordFloat,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .0
+            .partial_cmp(&self.0)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.1.cmp(&self.1))
+    }
+}
+
+// Orders by distance, then by index.
+fn sort<T>(points: &mut [(T, usize)])
+where
+    T: CoordFloat,
+{
+    points.sort_by(|a, b| {
+        a.0.partial_cmp(&b.0)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.1.cmp(&b.1))
+    });
+}
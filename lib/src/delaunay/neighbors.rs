@@ -0,0 +1,110 @@
+use delaunator::next_halfedge;
+use delaunator::EMPTY;
+use geo::CoordFloat;
+
+use super::Delaunay;
+
+/// Iterates over the points adjacent to a given point.
+///
+/// A port of the javascript generator function `delaunay.neighbors()`.
+pub struct Neighbors<'a, T>
+where
+    T: CoordFloat,
+{
+    delaunay: &'a Delaunay<T>,
+    i: usize,
+    // Yielded before the halfedge walk.
+    head: Option<usize>,
+    e0: usize,
+    // The next halfedge to visit, or EMPTY when the walk is complete.
+    e: usize,
+    // Yielded after the halfedge walk.
+    tail: Option<usize>,
+}
+
+impl<'a, T> Neighbors<'a, T>
+where
+    T: CoordFloat,
+{
+    pub(super) fn new(delaunay: &'a Delaunay<T>, i: usize) -> Self {
+        // degenerate case with several collinear points
+        if let Some(collinear) = &delaunay.collinear {
+            let l = delaunay.collinear_index[i];
+            let head = if l > 0 { Some(collinear[l - 1]) } else { None };
+            let tail = if l < collinear.len() - 1 {
+                Some(collinear[l + 1])
+            } else {
+                None
+            };
+            return Self {
+                delaunay,
+                i,
+                head,
+                e0: EMPTY,
+                e: EMPTY,
+                tail,
+            };
+        }
+
+        // EMPTY for a coincident point.
+        let e0 = delaunay.inedges.get(i).copied().unwrap_or(EMPTY);
+        Self {
+            delaunay,
+            i,
+            head: None,
+            e0,
+            e: e0,
+            tail: None,
+        }
+    }
+}
+
+impl<T> Iterator for Neighbors<'_, T>
+where
+    T: CoordFloat,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if let Some(head) = self.head.take() {
+            return Some(head);
+        }
+        if self.e == EMPTY {
+            return self.tail.take();
+        }
+
+        let triangles = &self.delaunay.delaunator.triangles;
+        let p0 = triangles[self.e];
+        if p0 == EMPTY {
+            // degenerate case: 1 distinct point.
+            self.e = EMPTY;
+            return None;
+        }
+
+        let mut e = if self.e % 3 == 2 {
+            self.e - 2
+        } else {
+            self.e + 1
+        };
+        if triangles[e] == self.i {
+            let out = e;
+            e = self.delaunay.delaunator.halfedges[e];
+            if e == EMPTY {
+                // The next point along the boundary. With two points the
+                // triangle is padded, as [h0, h1, h1], and leads back to i.
+                let p = triangles[next_halfedge(out)];
+                if p != p0 && p != self.i {
+                    self.tail = Some(p);
+                }
+            } else if e == self.e0 {
+                e = EMPTY;
+            }
+        } else {
+            // bad triangulation
+            e = EMPTY;
+        }
+        self.e = e;
+
+        Some(p0)
+    }
+}
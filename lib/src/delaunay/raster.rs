@@ -0,0 +1,133 @@
+//! Many nearest point searches at once, each search starting from the
+//! result of the one before.
+//!
+//! Successive queries which lie close together, as along the rows of an
+//! image, are answered in a few steps each.
+
+use delaunator::EMPTY;
+use geo::CoordFloat;
+use geo_types::Coord;
+use num_traits::FloatConst;
+use num_traits::FromPrimitive;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use super::Delaunay;
+
+#[cfg(feature = "rayon")]
+// The number of queries given to each task by par_find_many().
+const CHUNK: usize = 1024;
+
+impl<T> Delaunay<T>
+where
+    T: CoordFloat + FloatConst + FromPrimitive,
+{
+    /// Returns the index of the nearest point to each query, see
+    /// [`Delaunay::find`].
+    ///
+    /// Each search is started at the result of the previous one, so the
+    /// queries are best given in an order where neighbours are close.
+    /// Every index is EMPTY when there are no points.
+    pub fn find_many(&self, queries: &[Coord<T>]) -> Vec<usize> {
+        let mut i = 0;
+        queries
+            .iter()
+            .map(|p| {
+                let found = self.find(p, Some(i));
+                if found != EMPTY {
+                    i = found;
+                }
+                found
+            })
+            .collect()
+    }
+
+    /// Returns the index of the nearest point to the center of each pixel
+    /// of a width * height image, in row major order.
+    ///
+    /// The center of the pixel at (x, y) is (x + 0.5, y + 0.5), transform
+    /// maps it into the plane of the points. Each row is searched from the
+    /// start of the row above, then each pixel from the pixel before.
+    /// Every index is EMPTY when there are no points.
+    ///
+    /// # Panics
+    ///  Will never happen as constants will always be converted into T.
+    pub fn nearest_site_raster(
+        &self,
+        width: usize,
+        height: usize,
+        transform: impl Fn(&Coord<T>) -> Coord<T>,
+    ) -> Vec<usize> {
+        let mut labels = vec![EMPTY; width * height];
+        let mut hint = 0;
+        for (y, row) in labels.chunks_mut(width.max(1)).enumerate() {
+            hint = self.raster_row(row, y, hint, &transform);
+        }
+        labels
+    }
+
+    // Fills row y of the image, starting the first search at the hint.
+    // Returns the result at the start of the row, the hint for the next.
+    fn raster_row(
+        &self,
+        row: &mut [usize],
+        y: usize,
+        hint: usize,
+        transform: &impl Fn(&Coord<T>) -> Coord<T>,
+    ) -> usize {
+        let half = T::from(0.5_f64).unwrap();
+        let y = T::from(y).unwrap() + half;
+        let mut i = hint;
+        for (x, label) in row.iter_mut().enumerate() {
+            let p = transform(&Coord {
+                x: T::from(x).unwrap() + half,
+                y,
+            });
+            *label = self.find(&p, Some(i));
+            if *label != EMPTY {
+                i = *label;
+            }
+        }
+        row.first().copied().filter(|i| *i != EMPTY).unwrap_or(hint)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> Delaunay<T>
+where
+    T: CoordFloat + FloatConst + FromPrimitive + Send + Sync,
+{
+    /// A parallel [`Delaunay::find_many`].
+    ///
+    /// The queries are split into runs of consecutive queries, each run is
+    /// searched as by [`Delaunay::find_many`].
+    pub fn par_find_many(&self, queries: &[Coord<T>]) -> Vec<usize> {
+        queries
+            .par_chunks(CHUNK)
+            .flat_map_iter(|chunk| self.find_many(chunk))
+            .collect()
+    }
+
+    /// A parallel [`Delaunay::nearest_site_raster`].
+    ///
+    /// The rows are searched in parallel, the search for the first pixel of
+    /// each row starts at point 0.
+    ///
+    /// # Panics
+    ///  Will never happen as constants will always be converted into T.
+    pub fn par_nearest_site_raster(
+        &self,
+        width: usize,
+        height: usize,
+        transform: impl Fn(&Coord<T>) -> Coord<T> + Sync,
+    ) -> Vec<usize> {
+        let mut labels = vec![EMPTY; width * height];
+        labels
+            .par_chunks_mut(width.max(1))
+            .enumerate()
+            .for_each(|(y, row)| {
+                self.raster_row(row, y, 0, &transform);
+            });
+        labels
+    }
+}
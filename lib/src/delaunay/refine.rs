@@ -0,0 +1,532 @@
+//! Adds points to a triangulation until its triangles meet a [`Quality`].
+//!
+//! Ruppert, "A Delaunay refinement algorithm for quality 2-dimensional
+//! mesh generation", Journal of Algorithms 18 (1995).
+//!
+//! The segments are the constrained edges and the edges of the boundary.
+//! A segment is split at its midpoint when a point lies within its
+//! diametral circle. Otherwise a point is inserted at the circumcenter of
+//! each bad triangle, unless it would encroach a segment, which is split
+//! instead.
+//!
+//! Near a small angle between segments the splits follow concentric
+//! shells, and triangles with a seditious shortest edge are left alone, see
+//! Shewchuk, "Delaunay refinement algorithms for triangular mesh
+//! generation", Computational Geometry 22 (2002).
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use delaunator::next_halfedge;
+use delaunator::prev_halfedge;
+use delaunator::Triangulation;
+use delaunator::EMPTY;
+use geo::CoordFloat;
+use geo_types::Coord;
+
+use super::carve::boundary;
+use super::constrain::circumcenter;
+use super::constrain::in_circle;
+use super::constrain::key;
+use super::constrain::link;
+use super::constrain::orient;
+use super::constrain::Mesh;
+use crate::quality::Quality;
+
+/// Refines the triangulation in place, returning the number of points added.
+///
+/// The new points are appended to points, and each constraint which is
+/// split is replaced by its pieces.
+pub fn refine<T>(
+    delaunator: &mut Triangulation,
+    constrained: &[bool],
+    points: &mut Vec<Coord<T>>,
+    constraints: &mut Vec<(usize, usize)>,
+    quality: &Quality<T>,
+) -> usize
+where
+    T: CoordFloat,
+{
+    let triangles = &delaunator.triangles;
+    if triangles.is_empty() || triangles.contains(&EMPTY) {
+        return 0;
+    }
+
+    let mut out = vec![EMPTY; points.len()];
+    for (e, p) in triangles.iter().enumerate() {
+        out[*p] = e;
+    }
+    let fixed: HashSet<(usize, usize)> = (0..triangles.len())
+        .filter(|e| constrained.get(*e).copied().unwrap_or(false))
+        .map(|e| key(triangles[e], triangles[next_halfedge(e)]))
+        .collect();
+    let start = delaunator.hull.first().copied();
+
+    let sin = quality.min_angle.to_radians().sin();
+    let mut segments = Segments {
+        inputs: points.len(),
+        on: HashMap::new(),
+    };
+    let mut mesh = Mesh {
+        delaunator,
+        coords: Cow::Owned(core::mem::take(points)),
+        out,
+        fixed,
+    };
+
+    // Segments waiting to be split.
+    let mut encroached = VecDeque::new();
+    for e in 0..mesh.delaunator.triangles.len() {
+        if mesh.is_segment(e) && mesh.is_encroached(e) {
+            encroached.push_back(mesh.ends(e));
+        }
+    }
+    // Triangles waiting to be checked, indexes are reused as triangles change.
+    let mut pending: Vec<usize> =
+        (0..mesh.delaunator.triangles.len() / 3).rev().collect();
+
+    let mut added = 0;
+    while added < quality.max_points {
+        if let Some((u, v)) = encroached.pop_front() {
+            // The segment may have been split already.
+            let Some(e) = mesh.edge(u, v).filter(|e| mesh.is_segment(*e))
+            else {
+                continue;
+            };
+            let p = mesh.split(e, &mut segments);
+            added += 1;
+            mesh.queue(p, &mut pending, &mut encroached);
+            continue;
+        }
+
+        let Some(t) = pending.pop() else {
+            break;
+        };
+        if !mesh.is_bad(t, sin, quality.max_area) {
+            continue;
+        }
+        // Splitting would only shrink the triangles around a small angle.
+        let (x, y) = mesh.shortest(t);
+        if segments.is_seditious(&mesh.coords, x, y) {
+            continue;
+        }
+        let c = mesh.circumcenter(t);
+        match mesh.locate(t, c) {
+            Some(Ok(s)) => {
+                let segments = mesh.encroached_by(s, c);
+                if segments.is_empty() {
+                    let p = mesh.insert_point(s, c);
+                    added += 1;
+                    mesh.queue(p, &mut pending, &mut encroached);
+                } else {
+                    encroached.extend(segments);
+                    pending.push(t);
+                }
+            }
+            // The circumcenter is hidden behind a segment.
+            Some(Err(e)) => {
+                encroached.push_back(mesh.ends(e));
+                pending.push(t);
+            }
+            None => {}
+        }
+    }
+
+    if added > 0 && !constraints.is_empty() {
+        let mut fixed: Vec<(usize, usize)> =
+            mesh.fixed.iter().copied().collect();
+        fixed.sort_unstable();
+        *constraints = fixed;
+    }
+    *points = mesh.coords.into_owned();
+    if let Some(start) = start {
+        delaunator.hull =
+            boundary(&delaunator.triangles, &delaunator.halfedges, start);
+    }
+    added
+}
+
+impl<T> Mesh<'_, T>
+where
+    T: CoordFloat,
+{
+    // The points joined by halfedge e.
+    fn ends(&self, e: usize) -> (usize, usize) {
+        let triangles = &self.delaunator.triangles;
+        (triangles[e], triangles[next_halfedge(e)])
+    }
+
+    fn is_segment(&self, e: usize) -> bool {
+        let (u, v) = self.ends(e);
+        self.delaunator.halfedges[e] == EMPTY || self.fixed.contains(&key(u, v))
+    }
+
+    // Does the point opposite e, on either side, lie within the
+    // diametral circle of e?
+    fn is_encroached(&self, e: usize) -> bool {
+        let triangles = &self.delaunator.triangles;
+        let o = self.delaunator.halfedges[e];
+        let (u, v) = self.ends(e);
+        core::iter::once(e)
+            .chain((o != EMPTY).then_some(o))
+            .any(|f| {
+                let w = self.coords[triangles[prev_halfedge(f)]];
+                diametral(self.coords[u], self.coords[v], w)
+            })
+    }
+
+    // A triangle is bad when its smallest angle is too small, or it is
+    // too large.
+    fn is_bad(&self, t: usize, sin: T, max_area: Option<T>) -> bool {
+        let triangles = &self.delaunator.triangles;
+        if 3 * t >= triangles.len() {
+            return false;
+        }
+        let [a, b, c] = [0, 1, 2].map(|i| self.coords[triangles[3 * t + i]]);
+        let area2 = orient(a, b, c);
+        if area2 <= T::zero() {
+            // Too thin to place a circumcenter.
+            return false;
+        }
+        if let Some(max_area) = max_area {
+            if area2 > max_area + max_area {
+                return true;
+            }
+        }
+        // The smallest angle faces the shortest edge, and
+        // sin(angle) = area2 / (product of the other two edges).
+        let d2 = |p: Coord<T>, q: Coord<T>| {
+            (p.x - q.x) * (p.x - q.x) + (p.y - q.y) * (p.y - q.y)
+        };
+        let (la, lb, lc) = (d2(b, c), d2(c, a), d2(a, b));
+        let shortest = la.min(lb).min(lc);
+        la * lb * lc * sin * sin > area2 * area2 * shortest
+    }
+
+    fn circumcenter(&self, t: usize) -> Coord<T> {
+        let triangles = &self.delaunator.triangles;
+        let [a, b, c] = [0, 1, 2].map(|i| self.coords[triangles[3 * t + i]]);
+        circumcenter(a, b, c)
+    }
+
+    // Walks from triangle t towards p. Returns the triangle containing p,
+    // or the segment which blocks the way.
+    //
+    // None if the walk fails to arrive.
+    fn locate(
+        &self,
+        mut t: usize,
+        p: Coord<T>,
+    ) -> Option<Result<usize, usize>> {
+        let triangles = &self.delaunator.triangles;
+        for _ in 0..triangles.len() {
+            let exit = (3 * t..3 * t + 3).find(|e| {
+                let (u, v) = self.ends(*e);
+                orient(self.coords[u], self.coords[v], p) < T::zero()
+            });
+            match exit {
+                None => return Some(Ok(t)),
+                Some(e) if self.is_segment(e) => return Some(Err(e)),
+                Some(e) => t = self.delaunator.halfedges[e] / 3,
+            }
+        }
+        None
+    }
+
+    // The segments bounding the cavity of p, which p would encroach.
+    // The cavity is formed by the triangles, reachable from t without
+    // crossing a segment, whose circumcircle contains p.
+    fn encroached_by(&self, t: usize, p: Coord<T>) -> Vec<(usize, usize)> {
+        let triangles = &self.delaunator.triangles;
+        let halfedges = &self.delaunator.halfedges;
+        let mut segments = vec![];
+        let mut seen = HashSet::from([t]);
+        let mut stack = vec![t];
+        while let Some(t) = stack.pop() {
+            for (e, o) in (3 * t..).zip(&halfedges[3 * t..3 * t + 3]) {
+                let (u, v) = self.ends(e);
+                if self.is_segment(e) {
+                    if diametral(self.coords[u], self.coords[v], p) {
+                        segments.push((u, v));
+                    }
+                    continue;
+                }
+                let s = o / 3;
+                if seen.contains(&s) {
+                    continue;
+                }
+                let [a, b, c] =
+                    [0, 1, 2].map(|i| self.coords[triangles[3 * s + i]]);
+                if in_circle(a, b, c, p) {
+                    seen.insert(s);
+                    stack.push(s);
+                }
+            }
+        }
+        segments
+    }
+
+    // Queues the triangles around point p, and the segments they encroach.
+    fn queue(
+        &self,
+        p: usize,
+        pending: &mut Vec<usize>,
+        encroached: &mut VecDeque<(usize, usize)>,
+    ) {
+        for e in self.around(p) {
+            let t = e / 3;
+            pending.push(t);
+            for f in 3 * t..3 * t + 3 {
+                if self.is_segment(f) && self.is_encroached(f) {
+                    encroached.push_back(self.ends(f));
+                }
+            }
+        }
+    }
+
+    fn push(&mut self, p: Coord<T>) -> usize {
+        self.coords.to_mut().push(p);
+        self.out.push(EMPTY);
+        self.coords.len() - 1
+    }
+
+    // Inserts p into triangle t, or onto one of its edges.
+    fn insert_point(&mut self, t: usize, p: Coord<T>) -> usize {
+        let on_edge = (3 * t..3 * t + 3).find(|e| {
+            let (u, v) = self.ends(*e);
+            orient(self.coords[u], self.coords[v], p).is_zero()
+        });
+        let p = self.push(p);
+        match on_edge {
+            Some(e) => self.insert_on_edge(e, p),
+            None => self.insert_in_triangle(t, p),
+        }
+        p
+    }
+
+    // The points joined by the shortest edge of triangle t.
+    fn shortest(&self, t: usize) -> (usize, usize) {
+        let d2 = |e: usize| {
+            let (u, v) = self.ends(e);
+            let (p, q) = (self.coords[u], self.coords[v]);
+            (p.x - q.x) * (p.x - q.x) + (p.y - q.y) * (p.y - q.y)
+        };
+        let e = (3 * t + 1..3 * t + 3).fold(3 * t, |e, f| {
+            if d2(f) < d2(e) {
+                f
+            } else {
+                e
+            }
+        });
+        self.ends(e)
+    }
+
+    // Splits the segment e.
+    fn split(&mut self, e: usize, segments: &mut Segments) -> usize {
+        let (u, v) = self.ends(e);
+        let m = segments.split_point(&self.coords, u, v);
+        let p = self.push(m);
+        segments.on.insert(p, segments.input(u, v));
+        if self.fixed.remove(&key(u, v)) {
+            self.fixed.insert(key(u, p));
+            self.fixed.insert(key(p, v));
+        }
+        self.insert_on_edge(e, p);
+        p
+    }
+
+    // Splits triangle t into three around point p.
+    fn insert_in_triangle(&mut self, t: usize, p: usize) {
+        let triangles = &mut self.delaunator.triangles;
+        let halfedges = &mut self.delaunator.halfedges;
+        let (a, b, c) =
+            (triangles[3 * t], triangles[3 * t + 1], triangles[3 * t + 2]);
+        let hb = halfedges[3 * t + 1];
+        let hc = halfedges[3 * t + 2];
+
+        // t becomes a, b, p, followed by b, c, p and c, a, p.
+        let s = triangles.len();
+        let r = s + 3;
+        triangles[3 * t + 2] = p;
+        triangles.extend([b, c, p, c, a, p]);
+        halfedges.extend([EMPTY; 6]);
+        link(halfedges, s, hb);
+        link(halfedges, r, hc);
+        link(halfedges, 3 * t + 1, s + 2);
+        link(halfedges, s + 1, r + 2);
+        link(halfedges, r + 1, 3 * t + 2);
+
+        self.out[a] = 3 * t;
+        self.out[b] = s;
+        self.out[c] = r;
+        self.out[p] = 3 * t + 2;
+
+        self.legalize(3 * t);
+        self.legalize(s);
+        self.legalize(r);
+    }
+
+    // Splits halfedge e, and its twin, at point p.
+    fn insert_on_edge(&mut self, e: usize, p: usize) {
+        let triangles = &mut self.delaunator.triangles;
+        let halfedges = &mut self.delaunator.halfedges;
+        let o = halfedges[e];
+        let e1 = next_halfedge(e);
+        let e2 = prev_halfedge(e);
+        let (a, b, c) = (triangles[e], triangles[e1], triangles[e2]);
+        let h1 = halfedges[e1];
+
+        // a, b, c becomes a, p, c followed by p, b, c.
+        let s = triangles.len();
+        triangles[e1] = p;
+        triangles.extend([p, b, c]);
+        halfedges.extend([EMPTY; 3]);
+        link(halfedges, s + 1, h1);
+        link(halfedges, e1, s + 2);
+
+        self.out[a] = e;
+        self.out[b] = s + 1;
+        self.out[c] = e2;
+        self.out[p] = s;
+
+        if o == EMPTY {
+            link(halfedges, e, EMPTY);
+            self.legalize(e2);
+            self.legalize(s + 1);
+            return;
+        }
+
+        // b, a, d becomes b, p, d followed by p, a, d.
+        let o1 = next_halfedge(o);
+        let o2 = prev_halfedge(o);
+        let d = triangles[o2];
+        let g1 = halfedges[o1];
+        let r = s + 3;
+        triangles[o1] = p;
+        triangles.extend([p, a, d]);
+        halfedges.extend([EMPTY; 3]);
+        link(halfedges, r + 1, g1);
+        link(halfedges, o1, r + 2);
+        link(halfedges, e, r);
+        link(halfedges, s, o);
+
+        self.out[d] = o2;
+
+        self.legalize(e2);
+        self.legalize(s + 1);
+        self.legalize(o2);
+        self.legalize(r + 1);
+    }
+
+    // Flips halfedge a, and those around it, until the triangles facing
+    // the new point are delaunay, as in delaunator's legalize().
+    fn legalize(&mut self, a: usize) {
+        let mut stack = vec![a];
+        while let Some(a) = stack.pop() {
+            let b = self.delaunator.halfedges[a];
+            if b == EMPTY || self.is_segment(a) {
+                continue;
+            }
+            let triangles = &self.delaunator.triangles;
+            let pr = triangles[a];
+            let pl = triangles[next_halfedge(a)];
+            let p0 = triangles[prev_halfedge(a)];
+            let p1 = triangles[prev_halfedge(b)];
+            if self.in_circle(pr, pl, p0, p1) {
+                self.flip(a);
+                stack.push(a);
+                stack.push(next_halfedge(b));
+            }
+        }
+    }
+}
+
+// Does p lie strictly within the circle with diameter u, v?
+fn diametral<T>(u: Coord<T>, v: Coord<T>, p: Coord<T>) -> bool
+where
+    T: CoordFloat,
+{
+    (u.x - p.x) * (v.x - p.x) + (u.y - p.y) * (v.y - p.y) < T::zero()
+}
+
+// Tracks the input segment on which each added point lies.
+struct Segments {
+    // The number of points before refinement.
+    inputs: usize,
+    on: HashMap<usize, (usize, usize)>,
+}
+
+impl Segments {
+    // The input segment containing the segment u, v.
+    fn input(&self, u: usize, v: usize) -> (usize, usize) {
+        self.on
+            .get(&u)
+            .or_else(|| self.on.get(&v))
+            .copied()
+            .unwrap_or_else(|| key(u, v))
+    }
+
+    // Where to split the segment u, v.
+    //
+    // A segment with one input end is split on a concentric shell, at a
+    // power of two from that end, so that the splits on segments meeting
+    // at a small angle stay level with each other.
+    fn split_point<T>(
+        &self,
+        coords: &[Coord<T>],
+        u: usize,
+        v: usize,
+    ) -> Coord<T>
+    where
+        T: CoordFloat,
+    {
+        let two = T::one() + T::one();
+        let (o, w) = match (u < self.inputs, v < self.inputs) {
+            (true, false) => (coords[u], coords[v]),
+            (false, true) => (coords[v], coords[u]),
+            _ => return (coords[u] + coords[v]) / two,
+        };
+        let length = (w.x - o.x).hypot(w.y - o.y);
+        let mut d = two.powf((length / two).log2().round());
+        let three = two + T::one();
+        while d > two * length / three {
+            d = d / two;
+        }
+        while d < length / three {
+            d = d * two;
+        }
+        o + (w - o) * (d / length)
+    }
+
+    // Is x, y an edge between two segments which meet at an angle smaller
+    // than 60 degrees, joining points at the same distance from the apex?
+    fn is_seditious<T>(&self, coords: &[Coord<T>], x: usize, y: usize) -> bool
+    where
+        T: CoordFloat,
+    {
+        let (Some(sx), Some(sy)) = (self.on.get(&x), self.on.get(&y)) else {
+            return false;
+        };
+        if sx == sy {
+            return false;
+        }
+        let Some(o) =
+            [sx.0, sx.1].into_iter().find(|o| *o == sy.0 || *o == sy.1)
+        else {
+            return false;
+        };
+        let far = |s: &(usize, usize)| if s.0 == o { s.1 } else { s.0 };
+        let a = coords[far(sx)] - coords[o];
+        let b = coords[far(sy)] - coords[o];
+        let two = T::one() + T::one();
+        let acute =
+            two * (a.x * b.x + a.y * b.y) > a.x.hypot(a.y) * b.x.hypot(b.y);
+
+        let dx = (coords[x] - coords[o]).x.hypot((coords[x] - coords[o]).y);
+        let dy = (coords[y] - coords[o]).x.hypot((coords[y] - coords[o]).y);
+        let tolerance = T::epsilon().sqrt() * dx.max(dy);
+        acute && (dx - dy).abs() <= tolerance
+    }
+}
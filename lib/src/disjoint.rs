@@ -0,0 +1,38 @@
+//! Disjoint sets, for grouping items into connected components.
+
+/// A forest of sets over the items 0..n, each item starts in a set of its
+/// own.
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+}
+
+impl DisjointSet {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+        }
+    }
+
+    /// The root of the set holding i, halving the path along the way.
+    pub fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    /// Merges the sets holding a and b, returns false when they are the
+    /// same.
+    ///
+    /// The smaller root becomes the root of the merged set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        self.parent[a.max(b)] = a.min(b);
+        true
+    }
+}
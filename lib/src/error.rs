@@ -0,0 +1,80 @@
+//! Errors reported by the fallible constructors.
+//!
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+/// Reasons why a triangulation or diagram cannot be constructed.
+///
+/// More reasons may be added, so a match needs a wildcard arm.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DelaunayError {
+    /// The bounds are reversed or not finite.
+    InvalidBounds,
+    /// The clipping region has fewer than three distinct points, a
+    /// coordinate is not finite, or it is not convex.
+    InvalidRegion,
+    /// The point at the given index has a NaN or infinite coordinate.
+    NonFiniteCoordinate(usize),
+    /// A triangulation needs at least three distinct points.
+    ///
+    /// Holds the number of distinct points found.
+    TooFewDistinctPoints(usize),
+    /// The point at the given index could not be converted into a f64.
+    Conversion(usize),
+    /// The constraint at the given index refers to a missing or coincident
+    /// point, or joins a point to itself.
+    InvalidConstraint(usize),
+    /// The constraint at the given index crosses an earlier constraint.
+    IntersectingConstraint(usize),
+    /// The constraint at the given index could not be inserted, as rounding
+    /// left the walk or the edge flips without a way forward.
+    UnresolvedConstraint(usize),
+    /// There must be one weight for each point.
+    ///
+    /// Holds the number of weights found.
+    WeightCount(usize),
+    /// The weight at the given index is NaN or infinite.
+    NonFiniteWeight(usize),
+    /// The value of the node with the given id is negative or not finite.
+    InvalidValue(usize),
+}
+
+impl Display for DelaunayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidBounds => write!(f, "invalid bounds"),
+            Self::InvalidRegion => write!(f, "invalid clipping region"),
+            Self::NonFiniteCoordinate(i) => {
+                write!(f, "point {i} has a non-finite coordinate")
+            }
+            Self::TooFewDistinctPoints(n) => {
+                write!(f, "found {n} distinct points, at least 3 are required")
+            }
+            Self::Conversion(i) => {
+                write!(f, "point {i} cannot be converted into a f64")
+            }
+            Self::InvalidConstraint(k) => {
+                write!(f, "constraint {k} does not join two distinct points")
+            }
+            Self::IntersectingConstraint(k) => {
+                write!(f, "constraint {k} crosses an earlier constraint")
+            }
+            Self::UnresolvedConstraint(k) => {
+                write!(f, "constraint {k} could not be inserted")
+            }
+            Self::WeightCount(n) => {
+                write!(f, "found {n} weights, expected one for each point")
+            }
+            Self::NonFiniteWeight(i) => {
+                write!(f, "weight {i} is not finite")
+            }
+            Self::InvalidValue(id) => {
+                write!(f, "node {id} has a negative or non-finite value")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DelaunayError {}
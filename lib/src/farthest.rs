@@ -0,0 +1,284 @@
+//! Farthest-point voronoi diagrams.
+//!
+//! The farthest-point cell of a site holds the points of the plane for
+//! which it is the farthest site. Only the points of the convex hull have a
+//! cell, and every cell is unbounded, so the cells are clipped to bounds.
+//!
+//! See also [`Delaunay::farthest`] and [`Delaunay::enclosing_circle`].
+
+use core::fmt::Display;
+
+use geo::CoordFloat;
+use geo_types::Coord;
+use num_traits::FloatConst;
+use num_traits::FromPrimitive;
+
+use crate::bounds::Bounds;
+use crate::delaunay::Delaunay;
+use crate::error::DelaunayError;
+use crate::geometry::dist2;
+use crate::path::Path;
+use crate::polygon::Polygon;
+use crate::power::clip;
+use crate::power::neighbors;
+use crate::power::rectangle;
+use crate::power::render_cell;
+use crate::power::render_edges;
+use crate::power::Vertex;
+use crate::CanvasRenderingContext2d;
+
+/// A circle, see [`Delaunay::enclosing_circle`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Circle<T>
+where
+    T: CoordFloat,
+{
+    /// The center.
+    pub center: Coord<T>,
+    /// The radius.
+    pub radius: T,
+}
+
+impl<T> Circle<T>
+where
+    T: CoordFloat + FloatConst,
+{
+    /// Returns true if p lies inside the circle, or on its edge.
+    pub fn contains(&self, p: &Coord<T>) -> bool {
+        (p.x - self.center.x).hypot(p.y - self.center.y) <= self.radius
+    }
+
+    /// Wrapper function - a departure from the javascript version.
+    /// `render()` has been spit into two functions.
+    pub fn render_to_string(&self) -> String
+    where
+        T: Display,
+    {
+        let mut path = Path::<T>::default();
+        self.render(&mut path);
+        path.to_string()
+    }
+
+    /// Renders the circle to a [`CanvasRenderingContext2d`].
+    pub fn render(&self, context: &mut impl CanvasRenderingContext2d<T>) {
+        context.move_to(&Coord {
+            x: self.center.x + self.radius,
+            y: self.center.y,
+        });
+        context.arc(&self.center, self.radius, T::zero(), T::TAU());
+    }
+}
+
+/// The farthest-point voronoi diagram of the points of a triangulation.
+///
+/// Each cell is found by clipping the bounds against every other point of
+/// the hull, the cost grows as the square of the number of points on the
+/// hull.
+#[derive(Debug)]
+pub struct FarthestVoronoi<T>
+where
+    T: CoordFloat,
+{
+    /// The delaunay triangulation.
+    pub delaunay: Delaunay<T>,
+    /// The cells are clipped to these bounds.
+    pub bounds: Bounds<T>,
+    cells: Vec<Vec<Vertex<T>>>,
+}
+
+impl<T> FarthestVoronoi<T>
+where
+    T: CoordFloat + FloatConst + FromPrimitive,
+{
+    /// Given a delaunay object and a bounds construct a farthest-point
+    /// voronoi diagram.
+    ///
+    /// When bounds is None, [`Bounds::default`] is used.
+    ///
+    /// # Panics
+    ///  When the bounds are invalid, see [`FarthestVoronoi::try_new`].
+    pub fn new(delaunay: Delaunay<T>, bounds: Option<Bounds<T>>) -> Self {
+        Self::try_new(delaunay, bounds).expect("Invalid bounds")
+    }
+
+    /// Given a delaunay object and a bounds construct a farthest-point
+    /// voronoi diagram.
+    ///
+    /// When bounds is None, [`Bounds::default`] is used.
+    ///
+    /// # Errors
+    /// [`DelaunayError::InvalidBounds`] if the bounds are reversed or not finite.
+    pub fn try_new(
+        delaunay: Delaunay<T>,
+        bounds: Option<Bounds<T>>,
+    ) -> Result<Self, DelaunayError> {
+        let bounds = bounds.unwrap_or_default();
+        if !bounds.is_valid() {
+            return Err(DelaunayError::InvalidBounds);
+        }
+        let mut out = Self {
+            delaunay,
+            bounds,
+            cells: vec![],
+        };
+        out.init();
+        Ok(out)
+    }
+
+    /// Recomputes the diagram after `delaunay.points` has been modified in
+    /// place.
+    ///
+    /// # Panics
+    ///  When a constraint can no longer be inserted, see
+    ///  [`Delaunay::try_update`].
+    pub fn update(&mut self) -> &mut Self {
+        self.delaunay.update();
+        self.init();
+        self
+    }
+
+    fn init(&mut self) {
+        let points = &self.delaunay.points;
+        let hull: Vec<usize> = self.delaunay.hull().collect();
+        self.cells.clear();
+        self.cells.resize(points.len(), vec![]);
+        let two = T::one() + T::one();
+        for i in &hull {
+            let pi = points[*i];
+            let mut cell = rectangle(&self.bounds);
+            for j in &hull {
+                let pj = points[*j];
+                if pi == pj {
+                    if j < i {
+                        // A coincident point with a lower index takes it all.
+                        cell.clear();
+                        break;
+                    }
+                    continue;
+                }
+                // The half-plane where pi is no nearer than pj, a.x <= c.
+                let a = (pi - pj) * two;
+                let c = dist2(&pi, &Coord::zero()) - dist2(&pj, &Coord::zero());
+                cell = clip(&cell, a, c, *j);
+                if cell.is_empty() {
+                    break;
+                }
+            }
+            self.cells[*i] = cell;
+        }
+    }
+
+    /// Returns the index of the point whose cell contains p, the farthest
+    /// point from p.
+    ///
+    /// see [`Delaunay::farthest`].
+    pub fn find(&self, p: &Coord<T>) -> Option<usize> {
+        self.delaunay.farthest(p)
+    }
+
+    /// Returns true if the cell with the specified index i contains the specified point p.
+    pub fn contains(&self, i: usize, p: &Coord<T>) -> bool {
+        let points = &self.delaunay.points;
+        self.find(p).is_some_and(|j| {
+            j == i
+                || (i < points.len()
+                    && !self.cells[i].is_empty()
+                    && dist2(&points[i], p) == dist2(&points[j], p))
+        })
+    }
+
+    /// Returns an iterator over the indexes of the points whose cells share
+    /// an edge with cell i.
+    pub fn neighbors(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        neighbors(self.cells.get(i).map_or(&[][..], |c| &c[..]))
+    }
+
+    /// Wrapper function - a departure from the javascript version.
+    /// `render()` has been spit into two functions.
+    pub fn render_to_string(&self) -> String
+    where
+        T: Display,
+    {
+        let mut path = Path::<T>::default();
+        self.render(&mut path);
+        path.to_string()
+    }
+
+    /// Renders the edges between cells, each edge is drawn once.
+    pub fn render(&self, context: &mut impl CanvasRenderingContext2d<T>) {
+        render_edges(&self.cells, context);
+    }
+
+    /// Wrapper function - a departure from the javascript version.
+    /// `render_bounds()` has been spit into two functions.
+    pub fn render_bounds_to_string(&self) -> String
+    where
+        T: Display,
+    {
+        let mut path = Path::<T>::default();
+        self.render_bounds(&mut path);
+        path.to_string()
+    }
+
+    /// Renders bounds to a [`CanvasRenderingContext2d`].
+    pub fn render_bounds(
+        &self,
+        context: &mut impl CanvasRenderingContext2d<T>,
+    ) {
+        context.rect(
+            &self.bounds.min(),
+            self.bounds.width(),
+            self.bounds.height(),
+        );
+    }
+
+    /// Wrapper function - a departure from the javascript version.
+    /// `render_cell()` has been spit into two functions.
+    pub fn render_cell_to_string(&self, i: usize) -> String
+    where
+        T: Display,
+    {
+        let mut path = Path::default();
+        self.render_cell(i, &mut path);
+        path.to_string()
+    }
+
+    /// Renders cell i to a [`CanvasRenderingContext2d`].
+    ///
+    /// Draws nothing when the cell is empty, as for a point inside the
+    /// hull.
+    pub fn render_cell(
+        &self,
+        i: usize,
+        context: &mut impl CanvasRenderingContext2d<T>,
+    ) {
+        if let Some(cell) = self.cells.get(i) {
+            render_cell(cell, context);
+        }
+    }
+
+    /// Returns the points of cell i, as a closed ring.
+    ///
+    /// Empty when the cell is empty.
+    pub fn cell_polygon(&self, i: usize) -> Vec<Coord<T>> {
+        let mut polygon = Polygon::default();
+        self.render_cell(i, &mut polygon);
+        polygon.0
+    }
+
+    /// Returns an iterator over the non-empty cells.
+    ///
+    /// Each item is the index of the point and its cell.
+    pub fn cell_polygons(
+        &self,
+    ) -> impl Iterator<Item = (usize, geo_types::Polygon<T>)> + '_ {
+        (0..self.cells.len()).filter_map(|i| {
+            let cell = self.cell_polygon(i);
+            if cell.is_empty() {
+                None
+            } else {
+                Some((i, geo_types::Polygon::new(cell.into(), vec![])))
+            }
+        })
+    }
+}
@@ -0,0 +1,72 @@
+//! Measurements of points and rings, shared by the diagrams.
+
+use geo::CoordFloat;
+use geo_types::Coord;
+
+/// The squared distance between a and b.
+pub fn dist2<T>(a: &Coord<T>, b: &Coord<T>) -> T
+where
+    T: CoordFloat,
+{
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    dx * dx + dy * dy
+}
+
+/// Twice the shoelace area of the ring, positive when counterclockwise
+/// with y pointing up.
+pub fn signed_area<T>(ring: &[Coord<T>]) -> T
+where
+    T: CoordFloat,
+{
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .fold(T::zero(), |area, (a, b)| area + a.x * b.y - b.x * a.y)
+}
+
+/// The unsigned area of the ring and its centroid.
+///
+/// The centroid of a ring without area is its first point, or the origin
+/// when the ring is empty.
+///
+/// # Panics
+///  Will never happen as constants will always be converted into T.
+pub fn area_centroid<T>(ring: &[Coord<T>]) -> (T, Coord<T>)
+where
+    T: CoordFloat,
+{
+    let mut area = T::zero();
+    let mut c = Coord::zero();
+    for (a, b) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+        let cross = a.x * b.y - b.x * a.y;
+        area = area + cross;
+        c = c + (*a + *b) * cross;
+    }
+    if area.is_zero() {
+        return (T::zero(), ring.first().copied().unwrap_or_else(Coord::zero));
+    }
+    let three = T::from(3_f64).unwrap();
+    let two = T::one() + T::one();
+    (area.abs() / two, c / (three * area))
+}
+
+/// True if p lies strictly inside the convex ring, of either orientation.
+pub fn inside<T>(ring: &[Coord<T>], p: &Coord<T>) -> bool
+where
+    T: CoordFloat,
+{
+    let mut sign = T::zero();
+    for (a, b) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+        let cross = (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+        if cross.is_zero() && a != b {
+            return false;
+        }
+        if !cross.is_zero() {
+            if sign * cross < T::zero() {
+                return false;
+            }
+            sign = cross;
+        }
+    }
+    true
+}
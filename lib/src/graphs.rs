@@ -0,0 +1,282 @@
+#![allow(clippy::many_single_char_names)]
+//! Proximity graphs, each a subgraph of the delaunay triangulation.
+//!
+//! From the sparsest to the densest, every graph is contained in the next:
+//! the euclidean minimum spanning tree, the relative neighborhood graph,
+//! the Urquhart graph, the Gabriel graph and the triangulation itself. So
+//! each is found by filtering the edges of the triangulation, rather than
+//! by comparing every pair of points.
+//!
+//! With constraints, the graphs are computed from the edges of the
+//! constrained triangulation, which need not be those of the delaunay
+//! triangulation.
+
+use core::fmt::Display;
+
+use delaunator::next_halfedge;
+use delaunator::prev_halfedge;
+use delaunator::EMPTY;
+use geo::CoordFloat;
+use geo_types::Coord;
+use num_traits::FloatConst;
+use num_traits::FromPrimitive;
+
+use crate::delaunay::Delaunay;
+use crate::disjoint::DisjointSet;
+use crate::geometry::dist2;
+use crate::path::Path;
+use crate::CanvasRenderingContext2d;
+
+/// A graph whose vertices are the points of a triangulation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Graph<'a, T>
+where
+    T: CoordFloat,
+{
+    /// The points joined by the edges.
+    pub points: &'a [Coord<T>],
+    /// Pairs of indexes into `points`, the smaller index first, in sorted
+    /// order.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl<'a, T> Graph<'a, T>
+where
+    T: CoordFloat + FloatConst + FromPrimitive,
+{
+    fn new(points: &'a [Coord<T>], mut edges: Vec<(usize, usize)>) -> Self {
+        for (i, j) in &mut edges {
+            if *j < *i {
+                core::mem::swap(i, j);
+            }
+        }
+        edges.sort_unstable();
+        Self { points, edges }
+    }
+
+    /// Every edge of the triangulation.
+    ///
+    /// When all the points are collinear, each point is joined to the
+    /// next along the line.
+    #[must_use]
+    pub fn delaunay(delaunay: &'a Delaunay<T>) -> Self {
+        if let Some(path) = degenerate(delaunay) {
+            return Self::new(&delaunay.points, path);
+        }
+        let triangles = &delaunay.delaunator.triangles;
+        let edges = halfedges(delaunay)
+            .map(|e| (triangles[e], triangles[next_halfedge(e)]))
+            .collect();
+        Self::new(&delaunay.points, edges)
+    }
+
+    /// The Gabriel graph, joining two points when no other point lies in
+    /// the closed disk whose diameter is the edge between them.
+    ///
+    /// An edge of the triangulation belongs when the angles opposite it are
+    /// acute.
+    #[must_use]
+    pub fn gabriel(delaunay: &'a Delaunay<T>) -> Self {
+        if let Some(path) = degenerate(delaunay) {
+            return Self::new(&delaunay.points, path);
+        }
+        let triangles = &delaunay.delaunator.triangles;
+        let edges = halfedges(delaunay)
+            .filter(|e| is_gabriel(delaunay, *e))
+            .map(|e| (triangles[e], triangles[next_halfedge(e)]))
+            .collect();
+        Self::new(&delaunay.points, edges)
+    }
+
+    /// The relative neighborhood graph, joining two points p and q when no
+    /// other point is nearer to both of them than they are to each other.
+    ///
+    /// Each edge of the Gabriel graph is tested against the points nearer
+    /// to p than q is, found by walking out from p through the
+    /// triangulation.
+    #[must_use]
+    pub fn relative_neighborhood(delaunay: &'a Delaunay<T>) -> Self {
+        if let Some(path) = degenerate(delaunay) {
+            return Self::new(&delaunay.points, path);
+        }
+        let points = &delaunay.points;
+        let triangles = &delaunay.delaunator.triangles;
+        // The edge being tested when each point was last visited.
+        let mut visited = vec![EMPTY; points.len()];
+        let mut stack = vec![];
+        let edges = halfedges(delaunay)
+            .filter(|e| is_gabriel(delaunay, *e))
+            .filter(|e| {
+                let (p, q) = (triangles[*e], triangles[next_halfedge(*e)]);
+                let d = dist2(&points[p], &points[q]);
+                visited[p] = *e;
+                stack.clear();
+                stack.push(p);
+                while let Some(s) = stack.pop() {
+                    for r in delaunay.neighbors(s) {
+                        if visited[r] == *e
+                            || dist2(&points[p], &points[r]) >= d
+                        {
+                            continue;
+                        }
+                        if dist2(&points[q], &points[r]) < d {
+                            return false;
+                        }
+                        visited[r] = *e;
+                        stack.push(r);
+                    }
+                }
+                true
+            })
+            .map(|e| (triangles[e], triangles[next_halfedge(e)]))
+            .collect();
+        Self::new(points, edges)
+    }
+
+    /// The Urquhart graph, the triangulation without the longest edge of
+    /// each triangle.
+    ///
+    /// Of several edges of the same length, the first in the triangle is
+    /// removed.
+    #[must_use]
+    pub fn urquhart(delaunay: &'a Delaunay<T>) -> Self {
+        if let Some(path) = degenerate(delaunay) {
+            return Self::new(&delaunay.points, path);
+        }
+        let points = &delaunay.points;
+        let triangles = &delaunay.delaunator.triangles;
+        let halfedges = &delaunay.delaunator.halfedges;
+        let length = |e: usize| {
+            dist2(&points[triangles[e]], &points[triangles[next_halfedge(e)]])
+        };
+        let mut removed = vec![false; triangles.len()];
+        for t in 0..triangles.len() / 3 {
+            let longest = (3 * t..3 * t + 3)
+                .reduce(|a, b| if length(b) > length(a) { b } else { a })
+                .unwrap_or(3 * t);
+            removed[longest] = true;
+            if halfedges[longest] != EMPTY {
+                removed[halfedges[longest]] = true;
+            }
+        }
+        let edges = self::halfedges(delaunay)
+            .filter(|e| !removed[*e])
+            .map(|e| (triangles[e], triangles[next_halfedge(e)]))
+            .collect();
+        Self::new(points, edges)
+    }
+
+    /// The euclidean minimum spanning tree, the shortest set of edges
+    /// joining all the points.
+    ///
+    /// Kruskal's algorithm over the edges of the triangulation. A point
+    /// which coincides with another is left out.
+    #[must_use]
+    pub fn minimum_spanning_tree(delaunay: &'a Delaunay<T>) -> Self {
+        if let Some(path) = degenerate(delaunay) {
+            return Self::new(&delaunay.points, path);
+        }
+        let points = &delaunay.points;
+        let triangles = &delaunay.delaunator.triangles;
+        let mut candidates: Vec<(T, usize, usize)> = halfedges(delaunay)
+            .map(|e| {
+                let (p, q) = (triangles[e], triangles[next_halfedge(e)]);
+                (dist2(&points[p], &points[q]), p.min(q), p.max(q))
+            })
+            .collect();
+        candidates.sort_by(|a, b| {
+            a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+        let mut trees = DisjointSet::new(points.len());
+        let edges = candidates
+            .into_iter()
+            .filter(|(_, p, q)| trees.union(*p, *q))
+            .map(|(_, p, q)| (p, q))
+            .collect();
+        Self::new(points, edges)
+    }
+}
+
+impl<T> Graph<'_, T>
+where
+    T: CoordFloat,
+{
+    /// Wrapper function - a departure from the javascript version.
+    /// `render()` has been spit into two functions.
+    #[must_use]
+    pub fn render_to_string(&self) -> String
+    where
+        T: Display,
+    {
+        let mut path = Path::<T>::default();
+        self.render(&mut path);
+        path.to_string()
+    }
+
+    /// Renders the edges of the graph to a [`CanvasRenderingContext2d`].
+    pub fn render(&self, context: &mut impl CanvasRenderingContext2d<T>) {
+        for (i, j) in &self.edges {
+            context.move_to(&self.points[*i]);
+            context.line_to(&self.points[*j]);
+        }
+    }
+}
+
+// The graphs of fewer than three distinct points, or of collinear points,
+// where the triangulation does not hold the edges: each point is joined to
+// the next along the line.
+fn degenerate<T>(delaunay: &Delaunay<T>) -> Option<Vec<(usize, usize)>>
+where
+    T: CoordFloat + FloatConst + FromPrimitive,
+{
+    let hull = &delaunay.delaunator.hull;
+    let line: &[usize] = match &delaunay.collinear {
+        Some(collinear) => collinear,
+        None if hull.len() < 3 => hull,
+        None => return None,
+    };
+    let points = &delaunay.points;
+    let mut edges = vec![];
+    let mut last: Option<usize> = None;
+    for i in line {
+        match last {
+            Some(l) if points[l] == points[*i] => {}
+            Some(l) => {
+                edges.push((l, *i));
+                last = Some(*i);
+            }
+            None => last = Some(*i),
+        }
+    }
+    Some(edges)
+}
+
+// Each edge of the triangulation once, as one of its halfedges.
+fn halfedges<T>(delaunay: &Delaunay<T>) -> impl Iterator<Item = usize> + '_
+where
+    T: CoordFloat,
+{
+    let halfedges = &delaunay.delaunator.halfedges;
+    (0..halfedges.len())
+        .filter(|e| halfedges[*e] == EMPTY || *e < halfedges[*e])
+}
+
+// True if the angles opposite halfedge e, in the triangles either side,
+// are acute.
+fn is_gabriel<T>(delaunay: &Delaunay<T>, e: usize) -> bool
+where
+    T: CoordFloat,
+{
+    let points = &delaunay.points;
+    let triangles = &delaunay.delaunator.triangles;
+    let a = points[triangles[e]];
+    let b = points[triangles[next_halfedge(e)]];
+    let acute = |e: usize| {
+        let c = points[triangles[prev_halfedge(e)]];
+        let (u, v) = (a - c, b - c);
+        u.x * v.x + u.y * v.y > T::zero()
+    };
+    let o = delaunay.delaunator.halfedges[e];
+    acute(e) && (o == EMPTY || acute(o))
+}
@@ -14,9 +14,20 @@
 //!
 //! Repository [`rust_d3_geo`](<https://github.com/martinfrances107/rust_d3_delaunay>)
 
+pub mod bounds;
+pub mod clusters;
 pub mod delaunay;
+mod disjoint;
+pub mod error;
+pub mod farthest;
+mod geometry;
+pub mod graphs;
 pub mod path;
 pub mod polygon;
+pub mod power;
+pub mod quality;
+pub mod relax;
+pub mod treemap;
 pub mod voronoi;
 
 // #[cfg(feature = "bevy")]
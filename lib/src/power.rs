@@ -0,0 +1,504 @@
+//! Power diagrams, also known as weighted voronoi or Laguerre diagrams.
+//!
+//! The power of a point x with respect to site i is
+//! |x - points\[i\]|² - weights\[i\], and the cell of site i holds the
+//! points of the plane where its power is the smallest. With equal weights
+//! the cells are those of the voronoi diagram. A site with a larger weight
+//! claims more of the plane, a site may be left with an empty cell.
+
+use core::fmt::Display;
+
+use geo::CoordFloat;
+use geo_types::Coord;
+
+use crate::bounds::Bounds;
+use crate::error::DelaunayError;
+use crate::geometry::dist2;
+use crate::path::Path;
+use crate::polygon::Polygon;
+use crate::CanvasRenderingContext2d;
+
+/// A vertex of a cell, and the site across the edge to the next vertex.
+/// None when the edge lies along the bounds.
+pub(crate) type Vertex<T> = (Coord<T>, Option<usize>);
+
+/// A power diagram, clipped to bounds or to a convex region.
+///
+/// Each cell is found by clipping the bounds against the other sites,
+/// nearest first, until the rest are too far away to claim any of it.
+/// Sorting the other sites for every cell makes the cost O(n² log n).
+#[derive(Debug)]
+pub struct PowerDiagram<T>
+where
+    T: CoordFloat,
+{
+    /// The sites.
+    pub points: Vec<Coord<T>>,
+    /// The weight of each site, the square of the radius of its circle.
+    pub weights: Vec<T>,
+    /// The cells are clipped to these bounds.
+    pub bounds: Bounds<T>,
+    // The convex region the cells are clipped to, when not the bounds.
+    region: Option<Vec<Coord<T>>>,
+    cells: Vec<Vec<Vertex<T>>>,
+}
+
+impl<T> PowerDiagram<T>
+where
+    T: CoordFloat,
+{
+    /// Computes the power diagram of the weighted points.
+    ///
+    /// When bounds is None, [`Bounds::default`] is used.
+    ///
+    /// # Panics
+    ///  When the input is invalid, see [`PowerDiagram::try_new`].
+    pub fn new(
+        points: &[Coord<T>],
+        weights: &[T],
+        bounds: Option<Bounds<T>>,
+    ) -> Self {
+        Self::try_new(points, weights, bounds).expect("Invalid power diagram")
+    }
+
+    /// Computes the power diagram of the weighted points.
+    ///
+    /// When bounds is None, [`Bounds::default`] is used.
+    ///
+    /// # Errors
+    /// - [`DelaunayError::InvalidBounds`] if the bounds are reversed or not finite.
+    /// - [`DelaunayError::WeightCount`] if there is not one weight for each point.
+    /// - [`DelaunayError::NonFiniteCoordinate`] if a coordinate is NaN or infinite.
+    /// - [`DelaunayError::NonFiniteWeight`] if a weight is NaN or infinite.
+    pub fn try_new(
+        points: &[Coord<T>],
+        weights: &[T],
+        bounds: Option<Bounds<T>>,
+    ) -> Result<Self, DelaunayError> {
+        let bounds = bounds.unwrap_or_default();
+        if !bounds.is_valid() {
+            return Err(DelaunayError::InvalidBounds);
+        }
+        Self::try_init(points, weights, bounds, None)
+    }
+
+    /// Computes the power diagram of the weighted points, clipped to a
+    /// convex region.
+    ///
+    /// The cells are found by clipping against half-planes, so the region
+    /// must be convex, in either orientation. The holes of the region are
+    /// ignored, and `bounds` is set to its bounding box.
+    ///
+    /// # Errors
+    /// - [`DelaunayError::InvalidRegion`] if the region has fewer than three distinct points, a coordinate is not finite, or the region is not convex.
+    /// - [`DelaunayError::WeightCount`] if there is not one weight for each point.
+    /// - [`DelaunayError::NonFiniteCoordinate`] if a coordinate is NaN or infinite.
+    /// - [`DelaunayError::NonFiniteWeight`] if a weight is NaN or infinite.
+    pub fn try_with_region(
+        points: &[Coord<T>],
+        weights: &[T],
+        region: &geo_types::Polygon<T>,
+    ) -> Result<Self, DelaunayError> {
+        let mut ring: Vec<Coord<T>> = region.exterior().0.clone();
+        ring.dedup();
+        if ring.len() > 1 && ring.first() == ring.last() {
+            ring.pop();
+        }
+        let bounds = Bounds::from_points(&ring, T::zero())
+            .filter(|_| ring.len() >= 3 && convex(&ring))
+            .ok_or(DelaunayError::InvalidRegion)?;
+        Self::try_init(points, weights, bounds, Some(ring))
+    }
+
+    fn try_init(
+        points: &[Coord<T>],
+        weights: &[T],
+        bounds: Bounds<T>,
+        region: Option<Vec<Coord<T>>>,
+    ) -> Result<Self, DelaunayError> {
+        if weights.len() != points.len() {
+            return Err(DelaunayError::WeightCount(weights.len()));
+        }
+        if let Some(i) = points
+            .iter()
+            .position(|p| !(p.x.is_finite() && p.y.is_finite()))
+        {
+            return Err(DelaunayError::NonFiniteCoordinate(i));
+        }
+        if let Some(i) = weights.iter().position(|w| !w.is_finite()) {
+            return Err(DelaunayError::NonFiniteWeight(i));
+        }
+
+        let mut out = Self {
+            points: points.to_vec(),
+            weights: weights.to_vec(),
+            bounds,
+            region,
+            cells: Vec::with_capacity(points.len()),
+        };
+        out.init();
+        Ok(out)
+    }
+
+    /// Recomputes the diagram after `points` or `weights` have been
+    /// modified in place.
+    ///
+    /// # Panics
+    ///  When there is not one weight for each point.
+    pub fn update(&mut self) -> &mut Self {
+        assert_eq!(
+            self.weights.len(),
+            self.points.len(),
+            "There must be one weight for each point."
+        );
+        self.init();
+        self
+    }
+
+    fn init(&mut self) {
+        let wmax = self.weights.iter().copied().fold(T::neg_infinity(), T::max);
+        let mut order: Vec<usize> = Vec::with_capacity(self.points.len());
+        self.cells.clear();
+        for i in 0..self.points.len() {
+            let pi = self.points[i];
+            order.clear();
+            order.extend((0..self.points.len()).filter(|j| *j != i));
+            order.sort_by(|a, b| {
+                dist2(&pi, &self.points[*a])
+                    .partial_cmp(&dist2(&pi, &self.points[*b]))
+                    .unwrap_or(core::cmp::Ordering::Equal)
+            });
+
+            let mut cell: Vec<Vertex<T>> = if let Some(region) = &self.region {
+                region.iter().map(|v| (*v, None)).collect()
+            } else {
+                rectangle(&self.bounds)
+            };
+            for j in &order {
+                // Every point of the cell lies within r of the site, the
+                // remaining sites are too far away to claim any of it.
+                let r2 = cell
+                    .iter()
+                    .map(|(v, _)| dist2(v, &pi))
+                    .fold(T::zero(), T::max);
+                let (r, d) = (r2.sqrt(), dist2(&pi, &self.points[*j]).sqrt());
+                if d > r && (d - r) * (d - r) - wmax >= r2 - self.weights[i] {
+                    break;
+                }
+                cell = self.clip_power(&cell, i, *j);
+                if cell.is_empty() {
+                    break;
+                }
+            }
+            self.cells.push(cell);
+        }
+    }
+
+    // Clips the cell of site i to the half-plane where the power of site i
+    // is no greater than that of site j.
+    fn clip_power(
+        &self,
+        cell: &[Vertex<T>],
+        i: usize,
+        j: usize,
+    ) -> Vec<Vertex<T>> {
+        let (pi, pj) = (self.points[i], self.points[j]);
+        let (wi, wj) = (self.weights[i], self.weights[j]);
+        if pi == pj && (wj > wi || (wj == wi && j < i)) {
+            // A coincident site with at least the same weight takes it all.
+            return vec![];
+        }
+        let two = T::one() + T::one();
+        let a = (pj - pi) * two;
+        let c =
+            dist2(&pj, &Coord::zero()) - dist2(&pi, &Coord::zero()) - wj + wi;
+        clip(cell, a, c, j)
+    }
+
+    /// Returns the index of the site whose cell contains p.
+    ///
+    /// None when there are no sites.
+    pub fn find(&self, p: &Coord<T>) -> Option<usize> {
+        (0..self.points.len()).min_by(|a, b| {
+            self.power(*a, p)
+                .partial_cmp(&self.power(*b, p))
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })
+    }
+
+    /// Returns true if the cell with the specified index i contains the specified point p.
+    pub fn contains(&self, i: usize, p: &Coord<T>) -> bool {
+        self.find(p)
+            .is_some_and(|j| j == i || self.power(j, p) == self.power(i, p))
+    }
+
+    // The power of p with respect to site i.
+    fn power(&self, i: usize, p: &Coord<T>) -> T {
+        dist2(&self.points[i], p) - self.weights[i]
+    }
+
+    /// Returns an iterator over the indexes of the sites whose cells share
+    /// an edge with cell i.
+    pub fn neighbors(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        neighbors(self.cells.get(i).map_or(&[][..], |c| &c[..]))
+    }
+
+    /// Returns an iterator over the edges of cell i, each with the index of
+    /// the site across it, None along the bounds.
+    pub(crate) fn edges(
+        &self,
+        i: usize,
+    ) -> impl Iterator<Item = (Coord<T>, Coord<T>, Option<usize>)> + '_ {
+        let cell = self.cells.get(i).map_or(&[][..], |c| &c[..]);
+        cell.iter()
+            .zip(cell.iter().cycle().skip(1))
+            .map(|((v0, edge), (v1, _))| (*v0, *v1, *edge))
+    }
+
+    /// Wrapper function - a departure from the javascript version.
+    /// `render()` has been spit into two functions.
+    pub fn render_to_string(&self) -> String
+    where
+        T: Display,
+    {
+        let mut path = Path::<T>::default();
+        self.render(&mut path);
+        path.to_string()
+    }
+
+    /// Renders the edges between cells, each edge is drawn once.
+    pub fn render(&self, context: &mut impl CanvasRenderingContext2d<T>) {
+        render_edges(&self.cells, context);
+    }
+
+    /// Wrapper function - a departure from the javascript version.
+    /// `render_bounds()` has been spit into two functions.
+    pub fn render_bounds_to_string(&self) -> String
+    where
+        T: Display,
+    {
+        let mut path = Path::<T>::default();
+        self.render_bounds(&mut path);
+        path.to_string()
+    }
+
+    /// Renders bounds to a [`CanvasRenderingContext2d`].
+    ///
+    /// When the diagram is clipped to a region, the outline of the region
+    /// is drawn instead.
+    pub fn render_bounds(
+        &self,
+        context: &mut impl CanvasRenderingContext2d<T>,
+    ) {
+        if let Some((first, rest)) =
+            self.region.as_ref().and_then(|region| region.split_first())
+        {
+            context.move_to(first);
+            for v in rest {
+                context.line_to(v);
+            }
+            context.close_path();
+            return;
+        }
+        context.rect(
+            &self.bounds.min(),
+            self.bounds.width(),
+            self.bounds.height(),
+        );
+    }
+
+    /// Wrapper function - a departure from the javascript version.
+    /// `render_cell()` has been spit into two functions.
+    pub fn render_cell_to_string(&self, i: usize) -> String
+    where
+        T: Display,
+    {
+        let mut path = Path::default();
+        self.render_cell(i, &mut path);
+        path.to_string()
+    }
+
+    /// Renders cell i to a [`CanvasRenderingContext2d`].
+    ///
+    /// Draws nothing when the cell is empty.
+    pub fn render_cell(
+        &self,
+        i: usize,
+        context: &mut impl CanvasRenderingContext2d<T>,
+    ) {
+        if let Some(cell) = self.cells.get(i) {
+            render_cell(cell, context);
+        }
+    }
+
+    /// Returns the points of cell i, as a closed ring.
+    ///
+    /// Empty when the cell is empty.
+    pub fn cell_polygon(&self, i: usize) -> Vec<Coord<T>> {
+        let mut polygon = Polygon::default();
+        self.render_cell(i, &mut polygon);
+        polygon.0
+    }
+
+    /// Returns an iterator over the non-empty cells.
+    ///
+    /// Each item is the index of the site and its cell.
+    pub fn cell_polygons(
+        &self,
+    ) -> impl Iterator<Item = (usize, geo_types::Polygon<T>)> + '_ {
+        (0..self.points.len()).filter_map(|i| {
+            let cell = self.cell_polygon(i);
+            if cell.is_empty() {
+                None
+            } else {
+                Some((i, geo_types::Polygon::new(cell.into(), vec![])))
+            }
+        })
+    }
+}
+
+/// The cell of the bounds, before clipping.
+pub(crate) fn rectangle<T>(b: &Bounds<T>) -> Vec<Vertex<T>>
+where
+    T: CoordFloat,
+{
+    // In the same order as the cells of a voronoi diagram.
+    [
+        (b.xmax, b.ymin),
+        (b.xmax, b.ymax),
+        (b.xmin, b.ymax),
+        (b.xmin, b.ymin),
+    ]
+    .into_iter()
+    .map(|(x, y)| (Coord { x, y }, None))
+    .collect()
+}
+
+/// True if the ring turns the same way at every vertex, and goes around
+/// once. A straight vertex is allowed, a spike is not.
+fn convex<T>(ring: &[Coord<T>]) -> bool
+where
+    T: CoordFloat,
+{
+    let n = ring.len();
+    let mut turn = T::zero();
+    // The edges head right, then left, changing direction twice in all.
+    let mut heading = (0..n)
+        .map(|k| ring[(k + 1) % n].x - ring[k].x)
+        .rfind(|dx| !dx.is_zero())
+        .unwrap_or_else(T::zero);
+    let mut changes = 0;
+    for k in 0..n {
+        let u = ring[(k + 1) % n] - ring[k];
+        let v = ring[(k + 2) % n] - ring[(k + 1) % n];
+        let cross = u.x * v.y - u.y * v.x;
+        if cross.is_zero() {
+            if u.x * v.x + u.y * v.y < T::zero() {
+                return false;
+            }
+        } else {
+            if turn * cross < T::zero() {
+                return false;
+            }
+            turn = cross;
+        }
+        if !u.x.is_zero() {
+            if heading * u.x < T::zero() {
+                changes += 1;
+            }
+            heading = u.x;
+        }
+    }
+    !turn.is_zero() && changes <= 2
+}
+
+/// Clips a convex cell to the half-plane a.x <= c, the new edge is
+/// labelled with site j.
+pub(crate) fn clip<T>(
+    cell: &[Vertex<T>],
+    a: Coord<T>,
+    c: T,
+    j: usize,
+) -> Vec<Vertex<T>>
+where
+    T: CoordFloat,
+{
+    let side = |v: &Coord<T>| a.x * v.x + a.y * v.y - c;
+    let mut out = Vec::with_capacity(cell.len() + 1);
+    for (k, (v0, edge)) in cell.iter().enumerate() {
+        let (v1, _) = cell[(k + 1) % cell.len()];
+        let (s0, s1) = (side(v0), side(&v1));
+        let crossing = || *v0 + (v1 - *v0) * (s0 / (s0 - s1));
+        match (s0 <= T::zero(), s1 <= T::zero()) {
+            (true, true) => out.push((*v0, *edge)),
+            (true, false) => {
+                out.push((*v0, *edge));
+                out.push((crossing(), Some(j)));
+            }
+            (false, true) => out.push((crossing(), *edge)),
+            (false, false) => {}
+        }
+    }
+    out
+}
+
+/// The sites across the edges of a cell, each once.
+///
+/// A site which only touches a vertex of the cell is skipped.
+pub(crate) fn neighbors<T>(
+    cell: &[Vertex<T>],
+) -> impl Iterator<Item = usize> + '_
+where
+    T: CoordFloat,
+{
+    // Clipping may leave edges of zero length, which are not shared.
+    let shared = move |k: usize| {
+        cell[k]
+            .1
+            .filter(|_| cell[k].0 != cell[(k + 1) % cell.len()].0)
+    };
+    // The first occurrence of each neighbor, across an edge of some length.
+    (0..cell.len()).filter_map(move |k| {
+        shared(k).filter(|j| !(0..k).any(|l| shared(l) == Some(*j)))
+    })
+}
+
+/// Renders the edges between cells, each edge is drawn once.
+pub(crate) fn render_edges<T>(
+    cells: &[Vec<Vertex<T>>],
+    context: &mut impl CanvasRenderingContext2d<T>,
+) where
+    T: CoordFloat,
+{
+    for (i, cell) in cells.iter().enumerate() {
+        for (k, (v0, edge)) in cell.iter().enumerate() {
+            let (v1, _) = cell[(k + 1) % cell.len()];
+            if edge.is_some_and(|j| j > i) && *v0 != v1 {
+                context.move_to(v0);
+                context.line_to(&v1);
+            }
+        }
+    }
+}
+
+/// Renders a cell, skipping repeated vertices. Draws nothing when the cell
+/// is empty.
+pub(crate) fn render_cell<T>(
+    cell: &[Vertex<T>],
+    context: &mut impl CanvasRenderingContext2d<T>,
+) where
+    T: CoordFloat,
+{
+    let Some(((first, _), rest)) = cell.split_first() else {
+        return;
+    };
+    context.move_to(first);
+    let mut last = first;
+    for (v, _) in rest {
+        if v != last && v != first {
+            context.line_to(v);
+            last = v;
+        }
+    }
+    context.close_path();
+}
@@ -0,0 +1,41 @@
+//! Targets for the refinement of a triangulation, see [`Delaunay::refine`].
+//!
+//! [`Delaunay::refine`]: crate::delaunay::Delaunay::refine
+
+use geo::CoordFloat;
+
+/// The shape and size every triangle should meet after refinement.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quality<T>
+where
+    T: CoordFloat,
+{
+    /// The smallest angle allowed in a triangle, in degrees.
+    ///
+    /// Refinement is guaranteed to terminate for angles up to about 20.7
+    /// degrees, provided the angles between constraints are no smaller
+    /// than 60 degrees. Otherwise `max_points` bounds the work done.
+    pub min_angle: T,
+    /// The largest area allowed for a triangle.
+    pub max_area: Option<T>,
+    /// The maximum number of points to add.
+    pub max_points: usize,
+}
+
+impl<T> Default for Quality<T>
+where
+    T: CoordFloat,
+{
+    /// A minimum angle of 20 degrees, with no limit on the area.
+    ///
+    /// # Panics
+    ///  Will never happen as constants will always be converted into T.
+    fn default() -> Self {
+        Self {
+            min_angle: T::from(20_f64).unwrap(),
+            max_area: None,
+            max_points: 100_000,
+        }
+    }
+}
@@ -0,0 +1,277 @@
+#![allow(clippy::many_single_char_names)]
+
+//! Lloyd relaxation, moves each point towards the centroid of its voronoi cell.
+//!
+//! Repeated relaxation produces a centroidal voronoi tessellation. When a
+//! density is supplied the points gather where the density is high, as in
+//! weighted voronoi stippling.
+
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use delaunator::EMPTY;
+use geo::CoordFloat;
+use geo_types::Coord;
+use num_traits::FloatConst;
+use num_traits::FromPrimitive;
+
+use crate::voronoi::Voronoi;
+
+/// The weight given to each region of the plane.
+pub enum Density<'a, T>
+where
+    T: CoordFloat,
+{
+    /// Every region has the same weight.
+    ///
+    /// The centroid of each clipped cell is computed exactly.
+    Uniform,
+    /// A non-negative weight for each point in the plane.
+    ///
+    /// Integrated over the cell using the edge midpoints of a triangle fan.
+    /// The rule is exact up to degree two, so the centroid, which weights
+    /// x by the density, is exact for constant and linear densities.
+    Function(&'a dyn Fn(&Coord<T>) -> T),
+    /// A row major grid of non-negative weights, such as a greyscale image.
+    ///
+    /// The value at (x, y) covers the unit square with its top-left
+    /// corner at (x, y). Each value is assigned, as a whole, to the cell
+    /// containing the center of the square.
+    Raster {
+        /// Holds width * height values.
+        data: &'a [T],
+        /// The number of values in each row.
+        width: usize,
+        /// The number of rows.
+        height: usize,
+    },
+}
+
+impl<T> Debug for Density<'_, T>
+where
+    T: CoordFloat,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Uniform => f.write_str("Uniform"),
+            Self::Function(_) => f.write_str("Function(..)"),
+            Self::Raster { width, height, .. } => f
+                .debug_struct("Raster")
+                .field("width", width)
+                .field("height", height)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Settings for Lloyd relaxation.
+///
+/// ```
+/// use d3_delaunay_rs::delaunay::Delaunay;
+/// use d3_delaunay_rs::relax::Relax;
+/// use geo_types::Coord;
+///
+/// let points = vec![
+///     Coord { x: 10_f64, y: 10_f64 },
+///     Coord { x: 20_f64, y: 10_f64 },
+///     Coord { x: 10_f64, y: 20_f64 },
+/// ];
+/// let mut voronoi = Delaunay::new(&points).voronoi(None);
+/// let relax = Relax {
+///     iterations: 50,
+///     ..Relax::default()
+/// };
+/// let convergence = relax.run(&mut voronoi);
+/// assert!(convergence.iterations <= 50);
+/// ```
+#[derive(Debug)]
+pub struct Relax<'a, T>
+where
+    T: CoordFloat,
+{
+    /// The weight given to each region of the plane.
+    pub density: Density<'a, T>,
+    /// The maximum number of iterations.
+    pub iterations: usize,
+    /// Stop once no point moves further than this in an iteration.
+    pub threshold: T,
+    /// The fraction of the distance to the centroid moved in each iteration.
+    ///
+    /// Values between 1 and 2 over-relax and can speed up convergence.
+    pub omega: T,
+}
+
+impl<T> Default for Relax<'_, T>
+where
+    T: CoordFloat,
+{
+    /// Up to 100 iterations of unweighted relaxation.
+    ///
+    /// # Panics
+    ///  Will never happen as constants will always be converted into T.
+    fn default() -> Self {
+        Self {
+            density: Density::Uniform,
+            iterations: 100,
+            threshold: T::from(1e-6_f64).unwrap(),
+            omega: T::one(),
+        }
+    }
+}
+
+/// The state of the points when relaxation stopped.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Convergence<T> {
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// The furthest distance a point moved in the final iteration.
+    pub displacement: T,
+    /// True if the displacement fell to within the threshold.
+    pub converged: bool,
+}
+
+impl<T> Relax<'_, T>
+where
+    T: CoordFloat + Display + FloatConst + FromPrimitive,
+{
+    /// Relaxes the points of the diagram in place.
+    ///
+    /// After each iteration the diagram is recomputed with
+    /// [`Voronoi::update`], so the buffers are reused.
+    ///
+    /// # Panics
+    ///  When a raster density does not hold width * height values, or as
+    ///  [`Voronoi::update`].
+    pub fn run(&self, voronoi: &mut Voronoi<T>) -> Convergence<T> {
+        let mut convergence = Convergence {
+            iterations: 0,
+            displacement: T::zero(),
+            converged: false,
+        };
+        while convergence.iterations < self.iterations {
+            let centroids = self.centroids(voronoi);
+            let bounds = voronoi.bounds;
+            let mut displacement = T::zero();
+            for (p, c) in voronoi.delaunay.points.iter_mut().zip(centroids) {
+                // Over-relaxation can overshoot the clipping bounds.
+                let next = Coord {
+                    x: (p.x + (c.x - p.x) * self.omega)
+                        .max(bounds.xmin)
+                        .min(bounds.xmax),
+                    y: (p.y + (c.y - p.y) * self.omega)
+                        .max(bounds.ymin)
+                        .min(bounds.ymax),
+                };
+                displacement =
+                    displacement.max((next.x - p.x).hypot(next.y - p.y));
+                *p = next;
+            }
+            voronoi.update();
+
+            convergence.iterations += 1;
+            convergence.displacement = displacement;
+            if displacement <= self.threshold {
+                convergence.converged = true;
+                break;
+            }
+        }
+        convergence
+    }
+
+    /// Returns the weighted centroid of each cell, without moving the points.
+    ///
+    /// A point whose cell is empty or has no weight is its own centroid.
+    ///
+    /// # Panics
+    ///  When a raster density does not hold width * height values.
+    pub fn centroids(&self, voronoi: &Voronoi<T>) -> Vec<Coord<T>> {
+        let points = &voronoi.delaunay.points;
+        // Weighted sums of x, y and the total weight, for each cell.
+        let mut c = vec![Coord::zero(); points.len()];
+        let mut s = vec![T::zero(); points.len()];
+
+        match &self.density {
+            Density::Uniform => {
+                let one = |_: &Coord<T>| T::one();
+                for i in 0..points.len() {
+                    (c[i], s[i]) = integrate_cell(voronoi, i, &one);
+                }
+            }
+            Density::Function(f) => {
+                for i in 0..points.len() {
+                    (c[i], s[i]) = integrate_cell(voronoi, i, *f);
+                }
+            }
+            Density::Raster {
+                data,
+                width,
+                height,
+            } => {
+                assert_eq!(
+                    data.len(),
+                    width * height,
+                    "A raster must hold width * height values."
+                );
+                let half = T::from(0.5_f64).unwrap();
+                let labels = voronoi.delaunay.nearest_site_raster(
+                    *width,
+                    *height,
+                    |p| *p,
+                );
+                for (index, (w, i)) in data.iter().zip(labels).enumerate() {
+                    if i == EMPTY {
+                        continue;
+                    }
+                    let p = Coord {
+                        x: T::from(index % width).unwrap() + half,
+                        y: T::from(index / width).unwrap() + half,
+                    };
+                    s[i] = s[i] + *w;
+                    c[i] = c[i] + p * *w;
+                }
+            }
+        }
+
+        points
+            .iter()
+            .zip(c.iter().zip(s.iter()))
+            .map(|(p, (c, s))| if s.is_zero() { *p } else { *c / *s })
+            .collect()
+    }
+}
+
+/// Returns the weighted sum of the positions within cell i, and its weight.
+///
+/// The cell is split into a fan of triangles, f is sampled at the
+/// midpoints of the edges of each triangle.
+fn integrate_cell<T>(
+    voronoi: &Voronoi<T>,
+    i: usize,
+    f: &dyn Fn(&Coord<T>) -> T,
+) -> (Coord<T>, T)
+where
+    T: CoordFloat + Display + FloatConst + FromPrimitive,
+{
+    let cell = voronoi.cell_polygon(i);
+    let mut c = Coord::zero();
+    let mut s = T::zero();
+    if let Some((p0, rest)) = cell.split_first() {
+        let two = T::one() + T::one();
+        for edge in rest.windows(2) {
+            let (p1, p2) = (edge[0], edge[1]);
+            // Signed, the sign cancels out in the ratio.
+            let area = ((p1.x - p0.x) * (p2.y - p0.y)
+                - (p2.x - p0.x) * (p1.y - p0.y))
+                / two;
+            for m in [(*p0 + p1) / two, (p1 + p2) / two, (p2 + *p0) / two] {
+                let w = area * f(&m);
+                s = s + w;
+                c = c + m * w;
+            }
+        }
+    }
+    // Both sums lack a factor of 1/3, which cancels out in the ratio.
+    (c, s)
+}
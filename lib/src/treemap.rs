@@ -0,0 +1,341 @@
+//! Voronoi treemaps, nested power diagrams whose cell areas are
+//! proportional to the values of a hierarchy.
+//!
+//! Nocaj and Brandes, "Computing Voronoi Treemaps: Faster, Simpler, and
+//! Resolution-independent", Computer Graphics Forum 31 (2012).
+//!
+//! The children of a node share out its cell. Their sites start at
+//! scattered points within the cell, then each iteration steps every
+//! weight towards the target area of its cell and moves every site to the
+//! centroid of its cell.
+
+use std::collections::HashMap;
+
+use geo::CoordFloat;
+use geo_types::Coord;
+use geo_types::LineString;
+use geo_types::Polygon;
+
+use crate::error::DelaunayError;
+use crate::geometry::area_centroid;
+use crate::geometry::inside;
+use crate::power::PowerDiagram;
+
+/// A node of the hierarchy to be laid out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node<T> {
+    /// The key of the cell in the layout.
+    pub id: usize,
+    /// The size of a leaf, the value of a node with children is the sum of
+    /// the values of its children.
+    pub value: T,
+    /// The nodes nested within this node.
+    pub children: Vec<Self>,
+}
+
+impl<T> Node<T>
+where
+    T: CoordFloat,
+{
+    /// A node without children.
+    #[must_use]
+    pub const fn leaf(id: usize, value: T) -> Self {
+        Self {
+            id,
+            value,
+            children: vec![],
+        }
+    }
+
+    /// A node whose value is the sum of the values of its children.
+    #[must_use]
+    pub fn new(id: usize, children: Vec<Self>) -> Self {
+        let value = children.iter().fold(T::zero(), |sum, c| sum + c.value);
+        Self {
+            id,
+            value,
+            children,
+        }
+    }
+
+    // The value of a leaf, or the total of the leaves below.
+    fn total(&self) -> T {
+        if self.children.is_empty() {
+            self.value
+        } else {
+            self.children
+                .iter()
+                .fold(T::zero(), |sum, c| sum + c.total())
+        }
+    }
+}
+
+/// Lays out a hierarchy within a convex region.
+///
+/// # Example
+/// ```
+/// use geo_types::Rect;
+/// use d3_delaunay_rs::treemap::Node;
+/// use d3_delaunay_rs::treemap::Treemap;
+///
+/// let root = Node::new(
+///     0,
+///     vec![Node::leaf(1, 1.), Node::leaf(2, 2.), Node::leaf(3, 3.)],
+/// );
+/// let region = Rect::new((0., 0.), (100., 100.)).to_polygon();
+/// let cells = Treemap::default().layout(&root, &region).unwrap();
+/// assert_eq!(cells.len(), 4);
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Treemap<T> {
+    /// The maximum number of iterations spent on the children of each node.
+    pub iterations: usize,
+    /// Stop once the areas of the cells are within this fraction of the
+    /// area being shared out.
+    ///
+    /// Measured as the sum of the differences between the area of each
+    /// cell and its target.
+    pub tolerance: T,
+}
+
+impl<T> Default for Treemap<T>
+where
+    T: CoordFloat,
+{
+    /// Up to 100 iterations for each node, to within 1%.
+    ///
+    /// # Panics
+    ///  Will never happen as constants will always be converted into T.
+    fn default() -> Self {
+        Self {
+            iterations: 100,
+            tolerance: T::from(0.01_f64).unwrap(),
+        }
+    }
+}
+
+impl<T> Treemap<T>
+where
+    T: CoordFloat,
+{
+    /// Returns the cell of each node, keyed by its id.
+    ///
+    /// The root is given the region. A node whose value is zero is left
+    /// out, along with its children.
+    ///
+    /// # Errors
+    /// - [`DelaunayError::InvalidRegion`] if the region has fewer than three distinct points, a coordinate is not finite, or the region is not convex.
+    /// - [`DelaunayError::InvalidValue`] if the value of a leaf is negative or not finite.
+    pub fn layout(
+        &self,
+        root: &Node<T>,
+        region: &Polygon<T>,
+    ) -> Result<HashMap<usize, Polygon<T>>, DelaunayError> {
+        check(root)?;
+        // Fails early on an invalid region.
+        PowerDiagram::<T>::try_with_region(&[], &[], region)?;
+
+        let mut cells = HashMap::new();
+        if root.total() > T::zero() {
+            self.split(root, region.clone(), &mut cells)?;
+        }
+        Ok(cells)
+    }
+
+    // Shares out the cell of the node among its children, recursively.
+    fn split(
+        &self,
+        node: &Node<T>,
+        region: Polygon<T>,
+        cells: &mut HashMap<usize, Polygon<T>>,
+    ) -> Result<(), DelaunayError> {
+        let children: Vec<&Node<T>> = node
+            .children
+            .iter()
+            .filter(|c| c.total() > T::zero())
+            .collect();
+        let ring = &region.exterior().0;
+        let (area, _) = area_centroid(ring);
+        let child_cells = match children.len() {
+            0 => vec![],
+            1 => vec![region.clone()],
+            _ if area.is_zero() => {
+                vec![
+                    Polygon::new(LineString::new(vec![]), vec![]);
+                    children.len()
+                ]
+            }
+            _ => self.power_cells(&children, &region, area)?,
+        };
+        cells.insert(node.id, region);
+        for (child, cell) in children.iter().zip(child_cells) {
+            self.split(child, cell, cells)?;
+        }
+        Ok(())
+    }
+
+    // The cells of the children within the region, with areas
+    // proportional to their values.
+    fn power_cells(
+        &self,
+        children: &[&Node<T>],
+        region: &Polygon<T>,
+        area: T,
+    ) -> Result<Vec<Polygon<T>>, DelaunayError> {
+        let total = children.iter().fold(T::zero(), |sum, c| sum + c.total());
+        let targets: Vec<T> =
+            children.iter().map(|c| area * c.total() / total).collect();
+        let sites = scatter(region, children.len());
+        // Equal weights, the first diagram is a voronoi diagram.
+        let weights = vec![T::zero(); children.len()];
+        let mut power =
+            PowerDiagram::try_with_region(&sites, &weights, region)?;
+
+        for _ in 0..self.iterations {
+            let measures: Vec<(T, Coord<T>)> = (0..children.len())
+                .map(|i| area_centroid(&power.cell_polygon(i)))
+                .collect();
+            let error = measures
+                .iter()
+                .zip(&targets)
+                .fold(T::zero(), |sum, ((a, _), t)| sum + (*a - *t).abs());
+            if error <= self.tolerance * area {
+                break;
+            }
+
+            adapt(&mut power, &measures, &targets);
+            for (i, (a, c)) in measures.iter().enumerate() {
+                // An empty cell keeps its site.
+                if !a.is_zero() {
+                    power.points[i] = *c;
+                }
+            }
+            power.update();
+        }
+
+        Ok((0..children.len())
+            .map(|i| Polygon::new(power.cell_polygon(i).into(), vec![]))
+            .collect())
+    }
+}
+
+// Rejects leaves with a negative or non-finite value, zero is allowed.
+fn check<T>(node: &Node<T>) -> Result<(), DelaunayError>
+where
+    T: CoordFloat,
+{
+    let valid = node.value.is_finite() && node.value >= T::zero();
+    if node.children.is_empty() && !valid {
+        return Err(DelaunayError::InvalidValue(node.id));
+    }
+    node.children.iter().try_for_each(check)
+}
+
+// Moves each weight half a newton step towards the target area of its
+// cell.
+//
+// Raising weight i by dw moves the edge shared with site j towards j by
+// dw / 2d, where d is the distance between the sites, so the area of the
+// cell grows by dw times the sum of length / 2d over its edges.
+fn adapt<T>(
+    power: &mut PowerDiagram<T>,
+    measures: &[(T, Coord<T>)],
+    targets: &[T],
+) where
+    T: CoordFloat,
+{
+    let two = T::one() + T::one();
+    let n = power.points.len();
+    let mut weights = power.weights.clone();
+    for i in 0..n {
+        let pi = power.points[i];
+        let (a, _) = measures[i];
+        let rate = power
+            .edges(i)
+            .filter_map(|(v0, v1, edge)| {
+                let d = power.points[edge?] - pi;
+                let l = v1 - v0;
+                Some(l.x.hypot(l.y) / (two * d.x.hypot(d.y)))
+            })
+            .fold(T::zero(), |sum, r| sum + r);
+        weights[i] = if a.is_zero() || !rate.is_normal() {
+            // Just enough weight for site i to lie within its own cell.
+            (0..n)
+                .filter(|j| *j != i)
+                .map(|j| {
+                    let d = power.points[j] - pi;
+                    power.weights[j] - d.x * d.x - d.y * d.y
+                })
+                .fold(power.weights[i], T::max)
+        } else {
+            power.weights[i] + (targets[i] - a) / (two * rate)
+        };
+    }
+    power.weights = weights;
+}
+
+// Returns n distinct points within the convex region.
+//
+// The points are drawn from a Halton sequence over the bounding box of the
+// region, so the layout is deterministic.
+fn scatter<T>(region: &Polygon<T>, n: usize) -> Vec<Coord<T>>
+where
+    T: CoordFloat,
+{
+    let ring = &region.exterior().0;
+    let (min, max) = ring.iter().fold((ring[0], ring[0]), |(min, max), p| {
+        (
+            Coord {
+                x: min.x.min(p.x),
+                y: min.y.min(p.y),
+            },
+            Coord {
+                x: max.x.max(p.x),
+                y: max.y.max(p.y),
+            },
+        )
+    });
+    let (_, centroid) = area_centroid(ring);
+
+    let mut points = Vec::with_capacity(n);
+    let mut k = 1;
+    // The region has an area, so most regions accept a fair share of the
+    // sequence; the limit guards against slivers.
+    while points.len() < n && k < 1000 * n {
+        let p = Coord {
+            x: min.x + (max.x - min.x) * halton(k, 2),
+            y: min.y + (max.y - min.y) * halton(k, 3),
+        };
+        if inside(ring, &p) {
+            points.push(p);
+        }
+        k += 1;
+    }
+    // Otherwise fall back to points around the centroid of the sliver.
+    let step = (max.x - min.x).max(max.y - min.y) / T::from(1000 * n).unwrap();
+    while points.len() < n {
+        let offset = T::from(points.len()).unwrap() * step;
+        points.push(Coord {
+            x: centroid.x + offset,
+            y: centroid.y + offset,
+        });
+    }
+    points
+}
+
+// The k-th element of the van der Corput sequence in the base.
+fn halton<T>(mut k: usize, base: usize) -> T
+where
+    T: CoordFloat,
+{
+    let b = T::from(base).unwrap();
+    let mut f = T::one();
+    let mut out = T::zero();
+    while k > 0 {
+        f = f / b;
+        out = out + f * T::from(k % base).unwrap();
+        k /= base;
+    }
+    out
+}
@@ -13,13 +13,16 @@ use num_traits::FloatConst;
 use num_traits::FromPrimitive;
 use num_traits::Zero;
 
+use super::delaunay::grid;
+#[cfg(feature = "robust")]
+use super::delaunay::orient;
 use super::delaunay::Delaunay;
+use super::error::DelaunayError;
 use super::path::Path;
 use super::polygon::Polygon;
 use super::CanvasRenderingContext2d;
 
-/// xmin, ymin, xmax, ymax.
-pub type Bounds<T> = (T, T, T, T);
+pub use super::bounds::Bounds;
 
 /// Data stores for a voronoi mesh.
 #[derive(Debug)]
@@ -36,16 +39,8 @@ where
     /// A Vec<v0, v0, w0, w0, …> where each non-zero quadruple describes an open (infinite) cell on the outer hull,
     ///  giving the directions of two open half-lines.
     pub vectors: Vec<Coord<T>>,
-    /// Bounds component.
-    pub xmin: T,
-    /// Bounds component.
-    pub ymin: T,
-    /// Bounds component.
-    pub xmax: T,
-    /// Bounds component.
-    pub ymax: T,
-    #[cfg(feature = "Generator")]
-    pub cellPolygons: Generator,
+    /// The cells are clipped to these bounds.
+    pub bounds: Bounds<T>,
 }
 
 impl<T> Voronoi<T>
@@ -54,20 +49,13 @@ where
 {
     /// Given a delaunay object and a bounds construct a Voronoi object.
     ///
+    /// When bounds is None, [`Bounds::default`] is used.
+    ///
     /// # Panics
-    ///  Will never happen as constants will always be converted into T.
-    #[allow(clippy::similar_names)]
+    ///  When the bounds are invalid, see [`Voronoi::try_new`].
     pub fn new(delaunay: Delaunay<T>, bounds: Option<Bounds<T>>) -> Self {
-        let (xmin, ymin, xmax, ymax) = bounds.unwrap_or_else(|| {
-            (
-                T::zero(),
-                T::zero(),
-                T::from_f64(960f64).unwrap(),
-                T::from_f64(500f64).unwrap(),
-            )
-        });
-
-        assert!(!(xmax < xmin || ymax < ymin), "Invalid bounds");
+        let bounds = bounds.unwrap_or_default();
+        assert!(bounds.is_valid(), "Invalid bounds");
         let len = delaunay.points.len() * 2;
 
         let mut circumcenters = Vec::with_capacity(len);
@@ -84,29 +72,64 @@ where
             circumcenters,
             delaunay,
             vectors,
-            xmin,
-            ymin,
-            xmax,
-            ymax,
+            bounds,
         };
 
         v.init();
         v
     }
 
-    // pub fn update(&mut self) {
-    //     self.delaunay.update();
-    //     self.init();
-    // }
+    /// Given a delaunay object and a bounds construct a Voronoi object.
+    ///
+    /// # Errors
+    /// [`DelaunayError::InvalidBounds`] if the bounds are reversed or not finite.
+    pub fn try_new(
+        delaunay: Delaunay<T>,
+        bounds: Option<Bounds<T>>,
+    ) -> Result<Self, DelaunayError> {
+        if bounds.is_some_and(|b| !b.is_valid()) {
+            return Err(DelaunayError::InvalidBounds);
+        }
+        Ok(Self::new(delaunay, bounds))
+    }
+
+    /// Recomputes the diagram after `delaunay.points` has been modified in place.
+    ///
+    /// The `circumcenters` and `vectors` buffers are reused.
+    ///
+    /// # Panics
+    ///  When a constraint can no longer be inserted, see
+    ///  [`Voronoi::try_update`].
+    pub fn update(&mut self) -> &mut Self {
+        self.try_update()
+            .expect("A constraint could not be inserted again.")
+    }
+
+    /// Recomputes the diagram after `delaunay.points` has been modified in place.
+    ///
+    /// As [`Voronoi::update`], reporting a constraint which can no longer
+    /// be inserted. The diagram is still complete, without that constraint.
+    ///
+    /// # Errors
+    /// See [`Delaunay::try_update`].
+    pub fn try_update(&mut self) -> Result<&mut Self, DelaunayError> {
+        let result = self.delaunay.try_update().map(|_| ());
+        self.init();
+        result.map(|()| self)
+    }
 
     #[allow(clippy::similar_names)]
     #[allow(clippy::too_many_lines)]
     fn init(&mut self) {
+        let p_zero = Coord {
+            x: T::zero(),
+            y: T::zero(),
+        };
+
         // Compute circumcenters.
         let circumcenter_len = self.delaunay.delaunator.triangles.len() / 3;
-        // Cannot use a slice cos need to be determined at compile time.
-        // self.circumcenters = (self.circumcenters[0..circumcenter_len]).to_vec();
-        self.circumcenters.truncate(circumcenter_len);
+        // The number of triangles can change between calls to update().
+        self.circumcenters.resize(circumcenter_len, p_zero);
         let triangles = &self.delaunay.delaunator.triangles;
         let points = &self.delaunay.points;
         let hull = &self.delaunay.delaunator.hull;
@@ -115,7 +138,12 @@ where
         let mut j = 0usize;
         let n = triangles.len();
         if !n.is_zero() {
-            let t1e_minus_8 = T::from_f64(1e-8).unwrap();
+            // Without exact predicates a triangle with a tiny area is
+            // treated as having none, its circumcenter is unreliable.
+            #[cfg(not(feature = "robust"))]
+            let nearly_degenerate = |ab: T| ab.abs() < T::from(1e-8).unwrap();
+            #[cfg(feature = "robust")]
+            let nearly_degenerate = |_: T| false;
             let two = T::from_f64(2f64).unwrap();
             loop {
                 let (x1, y1) = match triangles.get(i) {
@@ -154,6 +182,28 @@ where
                 };
 
                 let ab = (dx * ey - dy * ex) * two;
+                // The exact sign, so only a triangle without area is
+                // degenerate.
+                #[cfg(feature = "robust")]
+                let ab = match (x1, y1, x2, y2, x3, y3) {
+                    (
+                        Some(x1),
+                        Some(y1),
+                        Some(x2),
+                        Some(y2),
+                        Some(x3),
+                        Some(y3),
+                    ) => {
+                        // orient() is the negative of the usual cross
+                        // product.
+                        -orient(
+                            Coord { x: x1, y: y1 },
+                            Coord { x: x2, y: y2 },
+                            Coord { x: x3, y: y3 },
+                        ) * two
+                    }
+                    _ => ab,
+                };
                 // Out of bound checking is x and y type values are bound of bounds
                 // following the js closely dx and ex become nan
                 // JS is weird !NAN === true
@@ -164,6 +214,12 @@ where
                     // the circumcenter is at the infinity, in a
                     // direction that is:
                     // 1. orthogonal to the halfedge.
+                    // The distance is that of d3-delaunay, whose output the
+                    // collinear tests match digit for digit. It stands in
+                    // for infinity, so the bounds must lie well within 1e9
+                    // of the points. With the robust feature this branch is
+                    // only reached by a triangle without area, the distance
+                    // is the same.
                     let mut a = T::from(1e9).unwrap();
                     // 2. points away from the center; since the list of triangles starts
                     // in the center, the first point of the first triangle
@@ -195,7 +251,7 @@ where
                     //NB if ab is not NAN then x1,y1 must be numbers.
                     let x1 = x1.unwrap();
                     let y1 = y1.unwrap();
-                    if ab.abs() < t1e_minus_8 {
+                    if nearly_degenerate(ab) {
                         // almost equal points (degenerate triangle)
                         // NB if ab is not NAN then x3,y3 must be numbers.
                         let x3 = x3.unwrap();
@@ -222,11 +278,8 @@ where
             }
         }
 
-        let p_zero = Coord {
-            x: T::zero(),
-            y: T::zero(),
-        };
-        self.vectors.fill(p_zero);
+        self.vectors.clear();
+        self.vectors.resize(points.len() * 2, p_zero);
         // deviation from JS ... resolves index out of bounds issues
         // indexing using a negative value in JS returns undefined.
         // causes panic in rust.
@@ -284,6 +337,11 @@ where
             return;
         }
 
+        if let Some(collinear) = &self.delaunay.collinear {
+            self.render_collinear(collinear, context);
+            return;
+        }
+
         for i in 0..self.delaunay.delaunator.halfedges.len() {
             let j = self.delaunay.delaunator.halfedges[i];
             if j < i || j == EMPTY {
@@ -315,6 +373,49 @@ where
         }
     }
 
+    // Degenerate case, all points are collinear: the diagram is the set of
+    // perpendicular bisectors between consecutive points along the line.
+    fn render_collinear(
+        &self,
+        collinear: &[usize],
+        context: &mut impl CanvasRenderingContext2d<T>,
+    ) {
+        let points = &self.delaunay.points;
+        let two = T::one() + T::one();
+        // Bisectors are extended beyond the bounds, and then clipped.
+        let extent = self.bounds.width() + self.bounds.height();
+        let mut distinct = collinear
+            .iter()
+            .filter(|i| self.delaunay.inedges[**i] != EMPTY);
+        let Some(mut a) = distinct.next() else {
+            return;
+        };
+        for b in distinct {
+            let pa = points[*a];
+            let pb = points[*b];
+            let m = Coord {
+                x: (pa.x + pb.x) / two,
+                y: (pa.y + pb.y) / two,
+            };
+            let dx = pa.y - pb.y;
+            let dy = pb.x - pa.x;
+            let s = (extent
+                + (m.x - self.bounds.xmin).abs()
+                + (m.y - self.bounds.ymin).abs())
+                / dx.hypot(dy);
+            let p0 = Coord {
+                x: m.x - dx * s,
+                y: m.y - dy * s,
+            };
+            let p1 = Coord {
+                x: m.x + dx * s,
+                y: m.y + dy * s,
+            };
+            self.render_segment(&p0, &p1, context);
+            a = b;
+        }
+    }
+
     /// Wrapper function - a departure from the javascript version.
     /// `render_bounds()` has been spit into two functions.
     /// rust expects variable type to be determined statically
@@ -335,11 +436,11 @@ where
     ) {
         context.rect(
             &Coord {
-                x: self.xmin,
-                y: self.ymin,
+                x: self.bounds.xmin,
+                y: self.bounds.ymin,
             },
-            self.xmax - self.xmin,
-            self.ymax - self.ymin,
+            self.bounds.width(),
+            self.bounds.height(),
         );
     }
     /// Wrapper function - a departure from the javascript version.
@@ -404,6 +505,93 @@ where
         polygon.0
     }
 
+    /// Returns an iterator over the non-empty clipped cells.
+    ///
+    /// Each item is the index of the input point and its cell.
+    pub fn cell_polygons(
+        &self,
+    ) -> impl Iterator<Item = (usize, geo_types::Polygon<T>)> + '_
+    where
+        T: CoordFloat + Display,
+    {
+        (0..self.delaunay.points.len()).filter_map(|i| {
+            let cell = self.cell_polygon(i);
+            if cell.is_empty() {
+                None
+            } else {
+                Some((i, geo_types::Polygon::new(cell.into(), vec![])))
+            }
+        })
+    }
+
+    /// Returns the natural neighbors of p, each with its Sibson coordinate.
+    ///
+    /// The coordinate of a point is the fraction of the cell p would have,
+    /// if it were inserted, taken from the cell of that point. The
+    /// coordinates sum to one.
+    ///
+    /// Returns None when p is outside the hull, all the points are
+    /// collinear, or p is not finite.
+    pub fn natural_neighbors(&self, p: &Coord<T>) -> Option<Vec<(usize, T)>> {
+        self.delaunay
+            .natural_neighbors(&self.circumcenters, p, &mut None)
+    }
+
+    /// Returns the value at p, interpolated from the values at its natural
+    /// neighbors.
+    ///
+    /// Unlike [`Delaunay::interpolate_linear`], the interpolated surface is
+    /// smooth except at the points.
+    ///
+    /// Returns None when p is outside the hull, see
+    /// [`Voronoi::natural_neighbors`].
+    ///
+    /// # Panics
+    ///  When values holds fewer values than there are points.
+    pub fn interpolate_natural(&self, values: &[T], p: &Coord<T>) -> Option<T> {
+        self.interpolate_natural_with_hint(values, p, &mut None)
+    }
+
+    /// Samples [`Voronoi::interpolate_natural`] over a grid of width *
+    /// height cells covering the bounds, in row major order.
+    ///
+    /// Each cell is sampled at its center.
+    ///
+    /// # Panics
+    ///  When values holds fewer values than there are points.
+    pub fn interpolate_natural_grid(
+        &self,
+        values: &[T],
+        bounds: &Bounds<T>,
+        width: usize,
+        height: usize,
+    ) -> Vec<Option<T>> {
+        let mut hint = None;
+        grid(bounds, width, height)
+            .map(|p| self.interpolate_natural_with_hint(values, &p, &mut hint))
+            .collect()
+    }
+
+    fn interpolate_natural_with_hint(
+        &self,
+        values: &[T],
+        p: &Coord<T>,
+        hint: &mut Option<usize>,
+    ) -> Option<T> {
+        assert!(
+            values.len() >= self.delaunay.points.len(),
+            "There must be a value for each point."
+        );
+        let neighbors =
+            self.delaunay
+                .natural_neighbors(&self.circumcenters, p, hint)?;
+        Some(
+            neighbors
+                .iter()
+                .fold(T::zero(), |v, (i, w)| v + values[*i] * *w),
+        )
+    }
+
     fn render_segment(
         &self,
         p0: &Coord<T>,
@@ -432,7 +620,29 @@ where
         self.delaunay.step(i, p) == i
     }
 
-    // TODO place neighbors* here() rustlang does not yet support generator functions.
+    /// Returns an iterator over the indexes of the cells that share an edge
+    /// with cell i.
+    ///
+    /// Unlike [`Delaunay::neighbors`], cells that would only touch outside
+    /// of the bounds are not considered to be neighbors.
+    pub fn neighbors(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        let ci = self.clip(i).unwrap_or_default();
+        self.delaunay.neighbors(i).filter(move |j| {
+            self.clip(*j).is_some_and(|cj| Self::common_edge(&ci, &cj))
+        })
+    }
+
+    // Two clipped cells are adjacent if they share an edge, traversed in
+    // opposite directions.
+    fn common_edge(ci: &VecDeque<Coord<T>>, cj: &VecDeque<Coord<T>>) -> bool {
+        let li = ci.len();
+        let lj = cj.len();
+        (0..li).any(|ai| {
+            (0..lj).any(|aj| {
+                ci[ai] == cj[aj] && ci[(ai + 1) % li] == cj[(aj + lj - 1) % lj]
+            })
+        })
+    }
 
     pub fn cell(&self, i: usize) -> Option<VecDeque<Coord<T>>> {
         let e0 = self.delaunay.inedges[i];
@@ -465,23 +675,26 @@ where
         if i == 0 && self.delaunay.delaunator.hull.len() == 1 {
             return Some(VecDeque::from(vec![
                 Coord {
-                    x: self.xmax,
-                    y: self.ymin,
+                    x: self.bounds.xmax,
+                    y: self.bounds.ymin,
                 },
                 Coord {
-                    x: self.xmax,
-                    y: self.ymax,
+                    x: self.bounds.xmax,
+                    y: self.bounds.ymax,
                 },
                 Coord {
-                    x: self.xmin,
-                    y: self.ymax,
+                    x: self.bounds.xmin,
+                    y: self.bounds.ymax,
                 },
                 Coord {
-                    x: self.xmin,
-                    y: self.ymin,
+                    x: self.bounds.xmin,
+                    y: self.bounds.ymin,
                 },
             ]));
         }
+        if self.delaunay.collinear.is_some() {
+            return self.clip_collinear(i);
+        }
         self.cell(i).map(|points| {
             #[allow(non_snake_case)]
             let V = &self.vectors;
@@ -501,6 +714,83 @@ where
         })
     }
 
+    // Degenerate case, all points are collinear.
+    //
+    // Deviation from JS, which clips the cells of the jittered triangulation.
+    // Here the cell is computed exactly, as the bounds cut by the
+    // perpendicular bisectors with the neighbors either side along the line.
+    #[allow(non_snake_case)]
+    fn clip_collinear(&self, i: usize) -> Option<VecDeque<Coord<T>>> {
+        if self.delaunay.inedges[i] == EMPTY {
+            // Coincident point.
+            return None;
+        }
+        let mut P = VecDeque::from(vec![
+            Coord {
+                x: self.bounds.xmax,
+                y: self.bounds.ymin,
+            },
+            Coord {
+                x: self.bounds.xmax,
+                y: self.bounds.ymax,
+            },
+            Coord {
+                x: self.bounds.xmin,
+                y: self.bounds.ymax,
+            },
+            Coord {
+                x: self.bounds.xmin,
+                y: self.bounds.ymin,
+            },
+        ]);
+        let pi = self.delaunay.points[i];
+        for j in self.delaunay.collinear_neighbors(i).into_iter().flatten() {
+            P = Self::clip_half_plane(&P, &pi, &self.delaunay.points[j]);
+        }
+        Some(P)
+    }
+
+    // Sutherland–Hodgman clipping of a convex polygon, retaining the half
+    // plane that is closer to pi than pj.
+    #[allow(non_snake_case)]
+    fn clip_half_plane(
+        P: &VecDeque<Coord<T>>,
+        pi: &Coord<T>,
+        pj: &Coord<T>,
+    ) -> VecDeque<Coord<T>> {
+        let mut out = VecDeque::with_capacity(P.len() + 1);
+        let Some(last) = P.back() else {
+            return out;
+        };
+        let two = T::one() + T::one();
+        let nx = pj.x - pi.x;
+        let ny = pj.y - pi.y;
+        let mx = (pi.x + pj.x) / two;
+        let my = (pi.y + pj.y) / two;
+        let side = |q: &Coord<T>| (q.x - mx) * nx + (q.y - my) * ny;
+
+        let mut p0 = *last;
+        let mut s0 = side(&p0);
+        for p1 in P {
+            let s1 = side(p1);
+            if (s0 < T::zero() && s1 > T::zero())
+                || (s0 > T::zero() && s1 < T::zero())
+            {
+                let t = s0 / (s0 - s1);
+                out.push_back(Coord {
+                    x: p0.x + (p1.x - p0.x) * t,
+                    y: p0.y + (p1.y - p0.y) * t,
+                });
+            }
+            if s1 <= T::zero() {
+                out.push_back(*p1);
+            }
+            p0 = *p1;
+            s0 = s1;
+        }
+        out
+    }
+
     #[allow(non_snake_case)]
     fn clip_finite(
         &self,
@@ -511,7 +801,6 @@ where
         let mut p1 = points[points.len() - 1];
         let mut c1 = self.regioncode(&p1);
         let mut e1 = 0;
-        let two = T::from_f64(2f64).unwrap();
         for point in points {
             let p0 = p1;
             p1 = *point;
@@ -578,29 +867,23 @@ where
                 let len = P.len();
                 self.edge(i, e0, e1, &mut P, len);
             }
-        } else if self.contains(
-            i,
-            &Coord {
-                x: (self.xmin + self.xmax) / two,
-                y: (self.ymin + self.ymax) / two,
-            },
-        ) {
+        } else if self.contains(i, &self.bounds.center()) {
             return VecDeque::from(vec![
                 Coord {
-                    x: self.xmax,
-                    y: self.ymin,
+                    x: self.bounds.xmax,
+                    y: self.bounds.ymin,
                 },
                 Coord {
-                    x: self.xmax,
-                    y: self.ymax,
+                    x: self.bounds.xmax,
+                    y: self.bounds.ymax,
                 },
                 Coord {
-                    x: self.xmin,
-                    y: self.ymax,
+                    x: self.bounds.xmin,
+                    y: self.bounds.ymax,
                 },
                 Coord {
-                    x: self.xmin,
-                    y: self.ymin,
+                    x: self.bounds.xmin,
+                    y: self.bounds.ymin,
                 },
             ]);
         }
@@ -631,17 +914,21 @@ where
             let c = if c0 == 0 { c1 } else { c0 };
 
             if c & 0b1000 != 0 {
-                x = p0.x + (p1.x - p0.x) * (self.ymax - p0.y) / (p1.y - p0.y);
-                y = self.ymax;
+                x = p0.x
+                    + (p1.x - p0.x) * (self.bounds.ymax - p0.y) / (p1.y - p0.y);
+                y = self.bounds.ymax;
             } else if c & 0b0100 != 0 {
-                x = p0.x + (p1.x - p0.x) * (self.ymin - p0.y) / (p1.y - p0.y);
-                y = self.ymin;
+                x = p0.x
+                    + (p1.x - p0.x) * (self.bounds.ymin - p0.y) / (p1.y - p0.y);
+                y = self.bounds.ymin;
             } else if c & 0b0010 != 0 {
-                y = p0.y + (p1.y - p0.y) * (self.xmax - p0.x) / (p1.x - p0.x);
-                x = self.xmax;
+                y = p0.y
+                    + (p1.y - p0.y) * (self.bounds.xmax - p0.x) / (p1.x - p0.x);
+                x = self.bounds.xmax;
             } else {
-                y = p0.y + (p1.y - p0.y) * (self.xmin - p0.x) / (p1.x - p0.x);
-                x = self.xmin;
+                y = p0.y
+                    + (p1.y - p0.y) * (self.bounds.xmin - p0.x) / (p1.x - p0.x);
+                x = self.bounds.xmin;
             }
             if c0 == 0 {
                 p1 = Coord { x, y };
@@ -674,7 +961,6 @@ where
         }
 
         P = self.clip_finite(i, &P);
-        let t2 = T::from_f64(2f64).unwrap();
         if !P.is_empty() {
             let mut n = P.len();
             let mut c0;
@@ -692,29 +978,23 @@ where
                     break;
                 }
             }
-        } else if self.contains(
-            i,
-            &Coord {
-                x: (self.xmin + self.xmax) / t2,
-                y: (self.ymin + self.ymax) / t2,
-            },
-        ) {
+        } else if self.contains(i, &self.bounds.center()) {
             P = VecDeque::from([
                 Coord {
-                    x: self.xmin,
-                    y: self.ymin,
+                    x: self.bounds.xmin,
+                    y: self.bounds.ymin,
                 },
                 Coord {
-                    x: self.xmax,
-                    y: self.ymin,
+                    x: self.bounds.xmax,
+                    y: self.bounds.ymin,
                 },
                 Coord {
-                    x: self.xmax,
-                    y: self.ymax,
+                    x: self.bounds.xmax,
+                    y: self.bounds.ymax,
                 },
                 Coord {
-                    x: self.xmin,
-                    y: self.ymax,
+                    x: self.bounds.xmin,
+                    y: self.bounds.ymax,
                 },
             ]);
         }
@@ -744,8 +1024,8 @@ where
                 0b0100 => {
                     // top
                     e0 = 0b0110;
-                    x = self.xmax;
-                    y = self.ymin;
+                    x = self.bounds.xmax;
+                    y = self.bounds.ymin;
                 }
 
                 0b0110 => {
@@ -756,8 +1036,8 @@ where
                 0b0010 => {
                     // right
                     e0 = 0b1010;
-                    x = self.xmax;
-                    y = self.ymax;
+                    x = self.bounds.xmax;
+                    y = self.bounds.ymax;
                 }
                 0b1010 => {
                     // bottom-right
@@ -767,8 +1047,8 @@ where
                 0b1000 => {
                     // bottom
                     e0 = 0b1001;
-                    x = self.xmin;
-                    y = self.ymax;
+                    x = self.bounds.xmin;
+                    y = self.bounds.ymax;
                 }
                 0b1001 => {
                     // bottom-left
@@ -778,8 +1058,8 @@ where
                 0b0001 => {
                     // left
                     e0 = 0b0101;
-                    x = self.xmin;
-                    y = self.ymin;
+                    x = self.bounds.xmin;
+                    y = self.bounds.ymin;
                 }
                 _ => {
                     panic!("unexpected code");
@@ -825,23 +1105,23 @@ where
         let mut y = T::zero();
         if vy < T::zero() {
             // top
-            if p0.y <= self.ymin {
+            if p0.y <= self.bounds.ymin {
                 return None;
             }
-            let c = (self.ymin - p0.y) / vy;
+            let c = (self.bounds.ymin - p0.y) / vy;
             if c < t {
-                y = self.ymin;
+                y = self.bounds.ymin;
                 t = c;
                 x = p0.x + t * vx;
             }
         } else if vy > T::zero() {
             // bottom
-            if p0.y >= self.ymax {
+            if p0.y >= self.bounds.ymax {
                 return None;
             }
-            let c = (self.ymax - p0.y) / vy;
+            let c = (self.bounds.ymax - p0.y) / vy;
             if c < t {
-                y = self.ymax;
+                y = self.bounds.ymax;
                 t = c;
                 x = p0.x + t * vx;
             }
@@ -849,23 +1129,23 @@ where
 
         if vx > T::zero() {
             // right
-            if p0.x >= self.xmax {
+            if p0.x >= self.bounds.xmax {
                 return None;
             }
-            let c = (self.xmax - p0.x) / vx;
+            let c = (self.bounds.xmax - p0.x) / vx;
             if c < t {
-                x = self.xmax;
+                x = self.bounds.xmax;
                 t = c;
                 y = p0.y + t * vy;
             }
         } else if vx < T::zero() {
             // left
-            if p0.x <= self.xmin {
+            if p0.x <= self.bounds.xmin {
                 return None;
             }
-            let c = (self.xmin - p0.x) / vx;
+            let c = (self.bounds.xmin - p0.x) / vx;
             if c < t {
-                x = self.xmin;
+                x = self.bounds.xmin;
                 t = c;
                 y = p0.y + t * vy;
             }
@@ -875,17 +1155,17 @@ where
 
     fn edgecode(&self, p: &Coord<T>) -> u8 {
         // Lower and upper nibbles.
-        let lower = if p.x == self.xmin {
+        let lower = if p.x == self.bounds.xmin {
             0b0001
-        } else if p.x == self.xmax {
+        } else if p.x == self.bounds.xmax {
             0b0010
         } else {
             0b0000
         };
 
-        let upper = if p.y == self.ymin {
+        let upper = if p.y == self.bounds.ymin {
             0b0100
-        } else if p.y == self.ymax {
+        } else if p.y == self.bounds.ymax {
             0b1000
         } else {
             0b0000
@@ -896,17 +1176,17 @@ where
 
     pub fn regioncode(&self, p: &Coord<T>) -> u8 {
         // Lower and upper nibbles.
-        let lower = if p.x < self.xmin {
+        let lower = if p.x < self.bounds.xmin {
             0b001
-        } else if p.x > self.xmax {
+        } else if p.x > self.bounds.xmax {
             0b0010
         } else {
             0b0000
         };
 
-        let upper = if p.y < self.ymin {
+        let upper = if p.y < self.bounds.ymin {
             0b0100
-        } else if p.y > self.ymax {
+        } else if p.y > self.bounds.ymax {
             0b1000
         } else {
             0b0000
@@ -0,0 +1,113 @@
+extern crate pretty_assertions;
+
+use geo::Area;
+use geo::Contains;
+use geo_types::Coord;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::delaunay::Delaunay;
+
+type DelaunayStub = Delaunay<f64>;
+
+// Not in the original, alpha shapes have no javascript equivalent.
+
+// The points of a unit grid, for which keep(x, y) is true.
+fn grid(
+    xs: core::ops::Range<i32>,
+    ys: core::ops::Range<i32>,
+    keep: impl Fn(i32, i32) -> bool,
+) -> Vec<Coord<f64>> {
+    let mut points = vec![];
+    for x in xs {
+        for y in ys.clone() {
+            if keep(x, y) {
+                points.push(Coord {
+                    x: f64::from(x),
+                    y: f64::from(y),
+                });
+            }
+        }
+    }
+    points
+}
+
+#[test]
+fn alpha_shape_of_an_l_shape() {
+    println!("alpha_shape() outlines an L shaped cloud, not its convex hull");
+    // [0, 4] x [0, 2] and [0, 2] x [2, 4].
+    let points = grid(0..5, 0..5, |x, y| x <= 2 || y <= 2);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+
+    let shape = delaunay.alpha_shape(0.75);
+    assert_eq!(shape.0.len(), 1);
+    // The inner corner is cut by a single triangle.
+    assert_eq!(shape.unsigned_area(), 12.5);
+    assert_eq!(shape.signed_area(), 12.5);
+    assert!(shape.0[0].interiors().is_empty());
+    assert!(!shape.contains(&Coord { x: 3.5, y: 3.5 }));
+
+    // Larger than every circumradius, the convex hull.
+    assert_eq!(delaunay.alpha_shape(100.).unsigned_area(), 14.);
+    assert!(delaunay.alpha_shape(0.5).0.is_empty());
+}
+
+#[test]
+fn alpha_shape_has_holes() {
+    println!("alpha_shape() leaves a hole where points are missing");
+    let points = grid(0..6, 0..6, |x, y| {
+        !((2..=3).contains(&x) && (2..=3).contains(&y))
+    });
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let shape = delaunay.alpha_shape(0.75);
+    assert_eq!(shape.0.len(), 1);
+    let polygon = &shape.0[0];
+    assert_eq!(polygon.interiors().len(), 1);
+    // Each corner of the hole is cut by a triangle.
+    assert_eq!(polygon.interiors()[0].0.len(), 9);
+    assert_eq!(polygon.unsigned_area(), 18.);
+    assert_eq!(polygon.signed_area(), 18.);
+}
+
+#[test]
+fn alpha_shape_has_components() {
+    println!("alpha_shape() returns a polygon for each cluster");
+    let mut points = grid(0..3, 0..3, |_, _| true);
+    points.extend(grid(10..13, 5..8, |_, _| true));
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let shape = delaunay.alpha_shape(0.75);
+    assert_eq!(shape.0.len(), 2);
+    for polygon in &shape.0 {
+        assert_eq!(polygon.unsigned_area(), 4.);
+    }
+
+    // The smallest alpha joining the clusters spans the gap between them.
+    let alpha = delaunay.smallest_connected_alpha().unwrap();
+    assert!(alpha > 4.);
+    assert_eq!(delaunay.alpha_shape(alpha).0.len(), 1);
+    assert_eq!(delaunay.alpha_shape(alpha * (1. - 1e-9)).0.len(), 2);
+}
+
+#[test]
+fn smallest_connected_alpha_covers_every_point() {
+    println!(
+        "smallest_connected_alpha() is the smallest alpha to reach every point"
+    );
+    let points = grid(0..5, 0..5, |x, y| x <= 2 || y <= 2);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let alpha = delaunay.smallest_connected_alpha().unwrap();
+    assert!((alpha - 0.5_f64.sqrt()).abs() < 1e-12);
+
+    let shape = delaunay.alpha_shape(alpha);
+    assert_eq!(shape.0.len(), 1);
+    for p in &points {
+        assert!(
+            shape.0[0].exterior().0.contains(p) || shape.contains(p),
+            "{p:?}"
+        );
+    }
+
+    let delaunay: DelaunayStub =
+        Delaunay::new(&[Coord { x: 0., y: 0. }, Coord { x: 1., y: 1. }]);
+    assert_eq!(delaunay.smallest_connected_alpha(), None);
+    assert!(delaunay.alpha_shape(1.).0.is_empty());
+}
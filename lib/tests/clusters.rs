@@ -0,0 +1,137 @@
+extern crate pretty_assertions;
+
+use geo::Area;
+use geo_types::Coord;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::clusters::Cut;
+use d3_delaunay_rs::delaunay::Delaunay;
+
+type DelaunayStub = Delaunay<f64>;
+
+// Not in the original, d3-delaunay has no clustering.
+
+// Scattered points, without a random number generator.
+fn scatter(n: usize) -> Vec<Coord<f64>> {
+    (0..n)
+        .map(|i| {
+            let t = i as f64;
+            Coord {
+                x: 50. + 40. * (t * 12.9898).sin() * (t * 4.1414).cos(),
+                y: 50. + 40. * (t * 78.233).sin(),
+            }
+        })
+        .collect()
+}
+
+// A square grid of n * n points, spaced apart and offset by (x, y).
+fn grid(n: usize, spacing: f64, x: f64, y: f64) -> Vec<Coord<f64>> {
+    (0..n * n)
+        .map(|k| Coord {
+            x: x + spacing * (k % n) as f64,
+            y: y + spacing * (k / n) as f64,
+        })
+        .collect()
+}
+
+#[test]
+fn clusters_by_length() {
+    println!("clusters() with a length matches single linkage");
+    let points = scatter(200);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let max = 4.;
+    let clusters = delaunay.clusters(Cut::Length(max));
+
+    // Label the points by flooding every pair within max of each other.
+    let mut expected = vec![usize::MAX; points.len()];
+    let mut len = 0;
+    for start in 0..points.len() {
+        if expected[start] != usize::MAX {
+            continue;
+        }
+        expected[start] = len;
+        let mut stack = vec![start];
+        while let Some(i) = stack.pop() {
+            for j in 0..points.len() {
+                let d = (points[i].x - points[j].x)
+                    .hypot(points[i].y - points[j].y);
+                if expected[j] == usize::MAX && d <= max {
+                    expected[j] = len;
+                    stack.push(j);
+                }
+            }
+        }
+        len += 1;
+    }
+    assert!(len > 1);
+    assert_eq!(clusters.labels, expected);
+    assert_eq!(clusters.len(), len);
+    for c in 0..clusters.len() {
+        assert!(clusters.members(c).iter().all(|i| clusters.labels[*i] == c));
+    }
+}
+
+#[test]
+fn clusters_by_local_mean() {
+    println!("clusters() with a local mean separates dense from sparse");
+    // A dense grid beside a sparse one, the gap between them is shorter
+    // than the spacing of the sparse grid.
+    let mut points = grid(5, 1., 0., 0.);
+    points.extend(grid(4, 8., 10., 0.));
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+
+    let clusters = delaunay.clusters(Cut::LocalMean(1.5));
+    assert_eq!(clusters.len(), 2);
+    assert!(clusters.labels[..25].iter().all(|c| *c == 0));
+    assert!(clusters.labels[25..].iter().all(|c| *c == 1));
+    let areas: Vec<f64> =
+        clusters.hulls().map(|hull| hull.unsigned_area()).collect();
+    assert_eq!(areas, vec![16., 576.]);
+
+    // An absolute length either splits the sparse grid or joins both.
+    assert_eq!(delaunay.clusters(Cut::Length(1.5)).len(), 17);
+    assert_eq!(delaunay.clusters(Cut::Length(8.)).len(), 1);
+}
+
+#[test]
+fn clusters_alpha_shapes() {
+    println!("clusters() gives an alpha shape for each cluster");
+    let mut points = grid(4, 1., 0., 0.);
+    points.extend(grid(4, 1., 10., 10.));
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let clusters = delaunay.clusters(Cut::Length(2.));
+    assert_eq!(clusters.len(), 2);
+    for shape in clusters.alpha_shapes(1.) {
+        assert_eq!(shape.0.len(), 1);
+        assert_eq!(shape.unsigned_area(), 9.);
+    }
+    assert!(clusters.alpha_shape(2, 1.).0.is_empty());
+    assert!(clusters.members(2).is_empty());
+}
+
+#[test]
+fn clusters_degenerate() {
+    println!("clusters() of collinear, coincident and missing points");
+    let points = vec![
+        Coord { x: 0., y: 0. },
+        Coord { x: 5., y: 0. },
+        Coord { x: 1., y: 0. },
+        Coord { x: 6., y: 0. },
+        Coord { x: 1., y: 0. },
+    ];
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let clusters = delaunay.clusters(Cut::Length(2.));
+    assert_eq!(clusters.labels, vec![0, 1, 0, 1, 0]);
+
+    // A coincident point joins the cluster of its twin.
+    let mut points = grid(3, 1., 0., 0.);
+    points.push(Coord { x: 20., y: 20. });
+    points.push(Coord { x: 1., y: 1. });
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let clusters = delaunay.clusters(Cut::Length(2.));
+    assert_eq!(clusters.labels, vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0]);
+    assert_eq!(clusters.members(1), &[9]);
+
+    let delaunay: DelaunayStub = Delaunay::new(&[]);
+    assert!(delaunay.clusters(Cut::LocalMean(2.)).is_empty());
+}
@@ -1,9 +1,12 @@
 extern crate pretty_assertions;
 
+use delaunator::next_halfedge;
+use delaunator::prev_halfedge;
 use delaunator::EMPTY;
 use geo_types::Coord;
 use pretty_assertions::assert_eq;
 
+use d3_delaunay_rs::bounds::Bounds;
 use d3_delaunay_rs::delaunay::Delaunay;
 use d3_delaunay_rs::path::Path;
 use d3_delaunay_rs::voronoi::Voronoi;
@@ -38,13 +41,10 @@ fn from_array() {
         vec![EMPTY, 5, EMPTY, EMPTY, EMPTY, 1]
     );
     assert_eq!(delaunay.inedges, vec![2, 4, 0, 3]);
-
-    // Cannot reproduce neighbors tests...neighbors is a generator function!
-
-    // test.deepEqual(Array.from(delaunay.neighbors(0)), [1, 2]);
-    //   test.deepEqual(Array.from(delaunay.neighbors(1)), [2, 0]);
-    //   test.deepEqual(Array.from(delaunay.neighbors(2)), [0, 1]);
-    //   test.deepEqual(Array.from(delaunay.neighbors(3)), []);
+    assert_eq!(delaunay.neighbors(0).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(delaunay.neighbors(1).collect::<Vec<_>>(), vec![3, 2, 0]);
+    assert_eq!(delaunay.neighbors(2).collect::<Vec<_>>(), vec![0, 1, 3]);
+    assert_eq!(delaunay.neighbors(3).collect::<Vec<_>>(), vec![2, 1]);
 }
 
 #[test]
@@ -60,14 +60,10 @@ fn handles_coincident_points() {
 
     let delaunay: DelaunayStub = Delaunay::new(&points);
     assert_eq!(delaunay.inedges, vec![2, 1, 0, EMPTY]);
-
-    // Cannot reproduce neighbors tests...
-    // neighbors is part of rust_d3_geo_voronoi.delaunay.
-
-    //   test.deepEqual(Array.from(delaunay.neighbors(0)), [1, 2]);
-    //   test.deepEqual(Array.from(delaunay.neighbors(1)), [2, 0]);
-    //   test.deepEqual(Array.from(delaunay.neighbors(2)), [0, 1]);
-    //   test.deepEqual(Array.from(delaunay.neighbors(3)), []);
+    assert_eq!(delaunay.neighbors(0).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(delaunay.neighbors(1).collect::<Vec<_>>(), vec![2, 0]);
+    assert_eq!(delaunay.neighbors(2).collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(delaunay.neighbors(3).collect::<Vec<_>>(), vec![]);
 }
 
 // fn delaunay_from_iterable() {
@@ -105,10 +101,10 @@ fn uses_the_default_bounds() {
     ];
 
     let voronoi: VoronoiStub = Delaunay::new(&points).voronoi(None);
-    assert_eq!(voronoi.xmin, 0f64);
-    assert_eq!(voronoi.ymin, 0f64);
-    assert_eq!(voronoi.xmax, 960f64);
-    assert_eq!(voronoi.ymax, 500f64);
+    assert_eq!(voronoi.bounds.xmin, 0f64);
+    assert_eq!(voronoi.bounds.ymin, 0f64);
+    assert_eq!(voronoi.bounds.xmax, 960f64);
+    assert_eq!(voronoi.bounds.ymax, 500f64);
 }
 
 #[test]
@@ -123,12 +119,12 @@ fn uses_the_specified_bounds() {
         Coord { x: 1f64, y: 1f64 },
     ];
 
-    let voronoi: VoronoiStub =
-        Delaunay::new(&points).voronoi(Some((-1f64, -1f64, 2f64, 2f64)));
-    assert_eq!(voronoi.xmin, -1f64);
-    assert_eq!(voronoi.ymin, -1f64);
-    assert_eq!(voronoi.xmax, 2f64);
-    assert_eq!(voronoi.ymax, 2f64);
+    let voronoi: VoronoiStub = Delaunay::new(&points)
+        .voronoi(Some(Bounds::new(-1f64, -1f64, 2f64, 2f64)));
+    assert_eq!(voronoi.bounds.xmin, -1f64);
+    assert_eq!(voronoi.bounds.ymin, -1f64);
+    assert_eq!(voronoi.bounds.xmax, 2f64);
+    assert_eq!(voronoi.bounds.ymax, 2f64);
 }
 
 #[test]
@@ -211,7 +207,7 @@ fn skips_cells_for_coincident_points() {
 fn for_zero_poiints_return_for_zero_points() {
     println!("delaunay.voronoi() for zero point returns expected values");
     let v: VoronoiStub =
-        Delaunay::new(&[]).voronoi(Some((-1f64, -1f64, 2f64, 2f64)));
+        Delaunay::new(&[]).voronoi(Some(Bounds::new(-1f64, -1f64, 2f64, 2f64)));
     assert_eq!(v.render_to_string(), "");
 }
 
@@ -243,7 +239,7 @@ fn for_one_point_returns_the_bounding_rectangle() {
     println!("delaunay.voronoi() for one point returns the bounding rectangle");
     let points = vec![Coord { x: 0., y: 0. }];
     let d: DelaunayStub = Delaunay::new(&points);
-    let voronoi = d.voronoi(Some((-1f64, -1f64, 2f64, 2f64)));
+    let voronoi = d.voronoi(Some(Bounds::new(-1f64, -1f64, 2f64, 2f64)));
     assert_eq!(voronoi.render_cell_to_string(0), "M2,-1L2,2L-1,2L-1,-1Z");
     assert_eq!(voronoi.render_to_string(), "");
 }
@@ -258,7 +254,7 @@ fn for_two_point() {
         Coord { x: 1f64, y: 0f64 },
     ];
     let d: DelaunayStub = Delaunay::new(&points);
-    let voronoi = d.voronoi(Some((-1f64, -1f64, 2f64, 2f64)));
+    let voronoi = d.voronoi(Some(Bounds::new(-1f64, -1f64, 2f64, 2f64)));
     assert_eq!(
         voronoi.render_cell_to_string(0),
         "M-1,2L-1,-1L0.5,-1L0.5,2Z"
@@ -274,7 +270,7 @@ fn for_two_point() {
         0
     );
     let d: DelaunayStub = Delaunay::new(&points);
-    let voronoi = d.voronoi(Some((-1f64, -1f64, 2f64, 2f64)));
+    let voronoi = d.voronoi(Some(Bounds::new(-1f64, -1f64, 2f64, 2f64)));
     assert_eq!(
         voronoi.delaunay.find(&Coord { x: 2_f64, y: 0_f64 }, None),
         1
@@ -288,6 +284,35 @@ fn for_two_point() {
 //   test.deepEqual(Array.from(voronoi.delaunay.neighbors(2)), [0]);
 // });
 
+#[test]
+fn for_collinear_points() {
+    println!("delaunay.voronoi() for collinear points");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 1f64, y: 0f64 },
+        Coord { x: -1f64, y: 0f64 },
+    ];
+    let voronoi: VoronoiStub = Delaunay::new(&points)
+        .voronoi(Some(Bounds::new(-1f64, -1f64, 2f64, 2f64)));
+    let mut neighbors = voronoi.delaunay.neighbors(0).collect::<Vec<_>>();
+    neighbors.sort_unstable();
+    assert_eq!(neighbors, vec![1, 2]);
+    assert_eq!(voronoi.delaunay.neighbors(1).collect::<Vec<_>>(), vec![0]);
+    assert_eq!(voronoi.delaunay.neighbors(2).collect::<Vec<_>>(), vec![0]);
+
+    // Not in the original, the cells are exact strips.
+    assert_eq!(
+        voronoi.cell_polygon(0),
+        vec![
+            Coord { x: -0.5, y: -1. },
+            Coord { x: 0.5, y: -1. },
+            Coord { x: 0.5, y: 2. },
+            Coord { x: -0.5, y: 2. },
+            Coord { x: -0.5, y: -1. },
+        ]
+    );
+}
+
 #[test]
 fn find_x_y_returns_index_for_specified_point() {
     println!(
@@ -322,15 +347,24 @@ fn find_works_with_one_or_two_points() {
     println!("delaunay.find(x, y) works with one or two points");
 
     let points = vec![Coord { x: 0., y: 1. }, Coord { x: 0., y: 2. }];
-    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let mut delaunay: DelaunayStub = Delaunay::new(&points);
     assert_eq!(points[delaunay.find(&Coord { x: 0., y: -1. }, None)].y, 1.);
     assert_eq!(points[delaunay.find(&Coord { x: 0., y: 2.2 }, None)].y, 2.);
 
-    // TODO must complete this test when update() is implemented.
-    // delaunay.points.fill(Coord { x: 0., y: 0. });
-    // delaunay.update();
-    // assert_eq(delaunay.find(&Coord { x: 0., y: -1 }, None), 0);
-    // assert_eq(delaunay.find(&Coord { x: 0., y: 1.2 }, None), 0);
+    delaunay.points.fill(Coord { x: 0., y: 0. });
+    delaunay.update();
+    assert_eq!(delaunay.find(&Coord { x: 0., y: -1. }, None), 0);
+    assert_eq!(delaunay.find(&Coord { x: 0., y: 1.2 }, None), 0);
+}
+
+// Not in the original, a point is never its own neighbor.
+#[test]
+fn neighbors_of_two_points() {
+    println!("delaunay.neighbors(i) with two points");
+    let points = vec![Coord { x: 0., y: 1. }, Coord { x: 3., y: 2. }];
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    assert_eq!(delaunay.neighbors(0).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(delaunay.neighbors(1).collect::<Vec<_>>(), vec![0]);
 }
 
 #[test]
@@ -347,6 +381,8 @@ fn find_works_with_collinear_points() {
         Coord { x: 0., y: 4. },
     ];
     let delaunay: DelaunayStub = Delaunay::new(&points);
+    // The input is not jittered.
+    assert_eq!(delaunay.points, points);
     assert_eq!(points[delaunay.find(&Coord { x: 0., y: -1. }, None)].y, 0.0);
     assert_eq!(points[delaunay.find(&Coord { x: 0., y: 1.2 }, None)].y, 1.);
 
@@ -371,6 +407,29 @@ fn find_works_with_collinear_points() {
 //   test.deepEqual([...delaunay.neighbors(2)], [ 1, 3 ]);
 // });
 
+#[test]
+fn find_works_with_collinear_points_2() {
+    println!("delaunay.find(x, y) works with collinear points 2");
+    let points: Vec<Coord<f64>> = (0..120)
+        .map(|i| Coord {
+            x: f64::from(i) * 4_f64,
+            y: f64::from(i) / 3_f64 + 100_f64,
+        })
+        .collect();
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    #[cfg(not(feature = "robust"))]
+    assert_eq!(delaunay.neighbors(2).collect::<Vec<_>>(), vec![1, 3]);
+    // With exact predicates the rounded thirds are not collinear, and the
+    // points are triangulated as they are.
+    #[cfg(feature = "robust")]
+    {
+        assert!(delaunay.collinear.is_none());
+        let mut neighbors: Vec<usize> = delaunay.neighbors(2).collect();
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, vec![0, 1, 3, 5, 85]);
+    }
+}
+
 #[test]
 fn find_works_with_collinear_points_large() {
     println!("delaunay.find(x, y) works with collinear points (large)");
@@ -406,62 +465,81 @@ fn find_works_with_collinear_points_large() {
 //   test.deepEqual(circumcenters2, Float64Array.from([ -150, 50, -250, -150, 50, -150, -150, -250 ]));
 // });
 
-// #[test]
-// fn allow_fast_updated() {
-//     println!("delaunay.update() allows fast updates");
-//     let points: Vec<Coord<f64>> = vec![
-//         Coord { x: 0_f64, y: 0_f64 },
-//         Coord {
-//             x: 300_f64,
-//             y: 0_f64,
-//         },
-//         Coord {
-//             x: 0_f64,
-//             y: 300_f64,
-//         },
-//         Coord {
-//             x: 300_f64,
-//             y: 300_f64,
-//         },
-//         Coord {
-//             x: 100_f64,
-//             y: 100_f64,
-//         },
-//     ];
-//     let mut delaunay = Delaunay::new(&points);
-//     let circumcenters1 = delaunay
-//         .voronoi(Some((-500_f64, -500_f64, 500_f64, 500_f64)))
-//         .circumcenters;
-
-//     // let len = points.len();
-//     // for i in 0..len {
-//     //     let p = delaunay.points[i].clone();
-//     //     delaunay.points[i] = p.neg();
-//     // }
-//     for p in delaunay.points.iter_mut() {
-//         *p = p.neg();
-//     }
-//     delaunay.update();
-
-//     let circumcenters2 = delaunay
-//         .voronoi(Some((-500_f64, -500_f64, 500_f64, 500_f64)))
-//         .circumcenters;
-
-//     assert_eq!(
-//         circumcenters1,
-//         vec![Coord {
-//             x: 150_f64,
-//             y: 50_f64
-//         },]
-//     );
-//     assert_eq!(
-//         circumcenters2,
-//         vec![Coord {
-//             x: -150_f64,
-//             y: 50_f64
-//         },]
-//     );
-// }
+#[test]
+fn allow_fast_updates() {
+    println!("delaunay.update() allows fast updates");
+    let points: Vec<Coord<f64>> = vec![
+        Coord { x: 0_f64, y: 0_f64 },
+        Coord {
+            x: 300_f64,
+            y: 0_f64,
+        },
+        Coord {
+            x: 0_f64,
+            y: 300_f64,
+        },
+        Coord {
+            x: 300_f64,
+            y: 300_f64,
+        },
+        Coord {
+            x: 100_f64,
+            y: 100_f64,
+        },
+    ];
+    let mut voronoi: VoronoiStub = Delaunay::new(&points)
+        .voronoi(Some(Bounds::new(-500_f64, -500_f64, 500_f64, 500_f64)));
+    let circumcenters1 = voronoi.circumcenters.clone();
+
+    for p in &mut voronoi.delaunay.points {
+        *p = -*p;
+    }
+    // The buffers of the diagram are recomputed in place.
+    let circumcenters2 = voronoi.update().circumcenters.clone();
+
+    assert_eq!(
+        circumcenters1,
+        vec![
+            Coord {
+                x: 150_f64,
+                y: -50_f64
+            },
+            Coord {
+                x: -50_f64,
+                y: 150_f64
+            },
+            Coord {
+                x: 250_f64,
+                y: 150_f64
+            },
+            Coord {
+                x: 150_f64,
+                y: 250_f64
+            },
+        ]
+    );
+    assert_eq!(
+        circumcenters2,
+        vec![
+            Coord {
+                x: -150_f64,
+                y: 50_f64
+            },
+            Coord {
+                x: 50_f64,
+                y: -150_f64
+            },
+            Coord {
+                x: -250_f64,
+                y: -150_f64
+            },
+            Coord {
+                x: -150_f64,
+                y: -250_f64
+            },
+        ]
+    );
+}
 
 // tape("delaunay.update() updates collinear points", test => {
 //   const delaunay = new Delaunay(Array.from({ length: 250 }).fill(0));
@@ -484,6 +562,43 @@ fn find_works_with_collinear_points_large() {
 //   test.equal(delaunay.collinear, undefined);
 // });
 
+#[test]
+fn update_updates_collinear_points() {
+    println!("delaunay.update() updates collinear points");
+    let mut delaunay: DelaunayStub =
+        Delaunay::new(&[Coord { x: 0_f64, y: 0_f64 }; 125]);
+    assert_eq!(delaunay.collinear, None);
+
+    let on_a_line = |i: usize| Coord {
+        x: 0_f64,
+        y: (2 * i + 1) as f64,
+    };
+    for (i, p) in delaunay.points.iter_mut().enumerate() {
+        *p = on_a_line(i);
+    }
+    delaunay.update();
+    assert_eq!(delaunay.collinear.as_ref().map(Vec::len), Some(125));
+
+    for (i, p) in delaunay.points.iter_mut().enumerate() {
+        *p = Coord {
+            x: ((2 * i) as f64).sin(),
+            y: ((2 * i + 1) as f64).sin(),
+        };
+    }
+    delaunay.update();
+    assert_eq!(delaunay.collinear, None);
+
+    for (i, p) in delaunay.points.iter_mut().enumerate() {
+        *p = on_a_line(i);
+    }
+    delaunay.update();
+    assert_eq!(delaunay.collinear.as_ref().map(Vec::len), Some(125));
+
+    delaunay.points.fill(Coord { x: 0_f64, y: 0_f64 });
+    delaunay.update();
+    assert_eq!(delaunay.collinear, None);
+}
+
 #[test]
 fn finds_concident_point() {
     println!("delaunay.find(x, y) with coincident point");
@@ -858,6 +973,51 @@ fn hull_context_is_closed() {
     assert_eq!(delaunay.render_hull_to_string(), "M0,1L1,1L1,0L0,0Z");
 }
 
+// Not in the original, the hull is defined for 0, 1 and 2 points.
+#[test]
+fn hull_polygon_handles_degenerate_input() {
+    println!("delaunay.hullPolygon() handles degenerate input");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 1f64, y: 0f64 },
+        Coord { x: 0f64, y: 1f64 },
+        Coord { x: 1f64, y: 1f64 },
+    ];
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    assert_eq!(delaunay.hull().collect::<Vec<_>>(), vec![2, 3, 1, 0]);
+    assert_eq!(
+        delaunay.hull_polygon().exterior().0,
+        vec![
+            Coord { x: 0., y: 1. },
+            Coord { x: 1., y: 1. },
+            Coord { x: 1., y: 0. },
+            Coord { x: 0., y: 0. },
+            Coord { x: 0., y: 1. },
+        ]
+    );
+    assert!(delaunay.hull_polygon().interiors().is_empty());
+
+    let delaunay: DelaunayStub = Delaunay::new(&[]);
+    assert_eq!(delaunay.hull().count(), 0);
+    assert!(delaunay.hull_line_string().0.is_empty());
+    assert!(delaunay.hull_polygon().exterior().0.is_empty());
+    assert_eq!(delaunay.render_hull_to_string(), "");
+
+    let delaunay: DelaunayStub = Delaunay::new(&points[..1]);
+    assert_eq!(delaunay.hull().collect::<Vec<_>>(), vec![0]);
+    assert_eq!(
+        delaunay.hull_line_string().0,
+        vec![Coord { x: 0., y: 0. }, Coord { x: 0., y: 0. }]
+    );
+
+    let delaunay: DelaunayStub = Delaunay::new(&points[..2]);
+    assert_eq!(delaunay.hull().count(), 2);
+    let ring = delaunay.hull_line_string();
+    assert!(ring.is_closed());
+    assert_eq!(ring.0.len(), 3);
+    assert_eq!(delaunay.hull_polygon().exterior(), &ring);
+}
+
 // triangle_polygons_generator() has no equivalent testing in the javascript original
 // It was used to bug hunt some otherwise untested code.
 //
@@ -932,3 +1092,596 @@ fn generator() {
     }
     assert_eq!(expected, actual);
 }
+
+// Not in the original, but compared against the d3 output for the same
+// triangulation, delaunay.triangles = [0, 2, 1, 2, 3, 1].
+//
+//   delaunay.renderTriangle(0, context) => "M0,0L0,1L1,0Z"
+//   delaunay.trianglePolygon(1) => [[0, 1], [1, 1], [1, 0], [0, 1]]
+#[test]
+fn triangle_polygons_are_closed() {
+    println!("delaunay.trianglePolygons() yields closed rings");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 1f64, y: 0f64 },
+        Coord { x: 0f64, y: 1f64 },
+        Coord { x: 1f64, y: 1f64 },
+    ];
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    assert_eq!(delaunay.delaunator.triangles, vec![0, 2, 1, 2, 3, 1]);
+
+    // Path renders with a fixed number of decimal places.
+    assert_eq!(
+        delaunay.render_triangle_to_string(0),
+        "M0.00,0.00L0.00,1.00L1.00,0.00Z"
+    );
+
+    let rings: Vec<Vec<Coord<f64>>> = delaunay
+        .triangle_polygons()
+        .map(|polygon| polygon.exterior().0.clone())
+        .collect();
+    assert_eq!(
+        rings,
+        vec![
+            vec![
+                Coord { x: 0., y: 0. },
+                Coord { x: 0., y: 1. },
+                Coord { x: 1., y: 0. },
+                Coord { x: 0., y: 0. },
+            ],
+            vec![
+                Coord { x: 0., y: 1. },
+                Coord { x: 1., y: 1. },
+                Coord { x: 1., y: 0. },
+                Coord { x: 0., y: 1. },
+            ],
+        ]
+    );
+    assert_eq!(delaunay.triangle_polygon(1).0, rings[1]);
+
+    let triangles: Vec<_> = delaunay.triangles().collect();
+    assert_eq!(triangles.len(), 2);
+    assert_eq!(triangles[1], delaunay.triangle(1));
+    assert_eq!(
+        triangles[1],
+        geo_types::Triangle::unchecked_winding(
+            rings[1][0],
+            rings[1][1],
+            rings[1][2]
+        )
+    );
+
+    // A single point has no triangles.
+    let delaunay: DelaunayStub = Delaunay::new(&points[..1]);
+    assert_eq!(delaunay.triangle_polygons().count(), 0);
+    assert_eq!(delaunay.triangles().count(), 0);
+}
+
+// Not in the original, try_new() rejects bad input rather than panicking.
+#[test]
+fn try_new_rejects_invalid_input() {
+    use d3_delaunay_rs::error::DelaunayError;
+
+    println!("Delaunay::try_new() rejects invalid input");
+    let square = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 1f64, y: 0f64 },
+        Coord { x: 0f64, y: 1f64 },
+        Coord { x: 1f64, y: 1f64 },
+    ];
+    assert!(Delaunay::try_new(&square).is_ok());
+
+    let mut points = square.clone();
+    points[2].y = f64::NAN;
+    assert_eq!(
+        Delaunay::try_new(&points).unwrap_err(),
+        DelaunayError::NonFiniteCoordinate(2)
+    );
+    points[2].y = f64::INFINITY;
+    assert_eq!(
+        Delaunay::try_new(&points).unwrap_err(),
+        DelaunayError::NonFiniteCoordinate(2)
+    );
+
+    let coincident = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 1f64, y: 0f64 },
+        Coord { x: 1f64, y: 0f64 },
+    ];
+    assert_eq!(
+        Delaunay::try_new(&coincident).unwrap_err(),
+        DelaunayError::TooFewDistinctPoints(2)
+    );
+    assert_eq!(
+        DelaunayStub::try_new(&[]).unwrap_err(),
+        DelaunayError::TooFewDistinctPoints(0)
+    );
+
+    let voronoi =
+        Delaunay::try_new(&square)
+            .unwrap()
+            .try_voronoi(Some(Bounds {
+                xmin: 2f64,
+                ymin: -1f64,
+                xmax: -1f64,
+                ymax: 2f64,
+            }));
+    assert_eq!(voronoi.unwrap_err(), DelaunayError::InvalidBounds);
+    let voronoi = VoronoiStub::try_new(
+        Delaunay::new(&square),
+        Some(Bounds {
+            xmin: -1f64,
+            ymin: -1f64,
+            xmax: f64::NAN,
+            ymax: 2f64,
+        }),
+    );
+    assert_eq!(voronoi.unwrap_err(), DelaunayError::InvalidBounds);
+    assert!(VoronoiStub::try_new(
+        Delaunay::new(&square),
+        Some(Bounds::new(-1f64, -1f64, 2f64, 2f64))
+    )
+    .is_ok());
+}
+
+// Not in the original.
+#[test]
+fn bounds_helpers() {
+    use d3_delaunay_rs::error::DelaunayError;
+    use geo_types::Rect;
+
+    println!("Bounds validates and provides helpers");
+    assert_eq!(
+        Bounds::try_new(2f64, -1f64, -1f64, 2f64),
+        Err(DelaunayError::InvalidBounds)
+    );
+    assert_eq!(
+        Bounds::try_new(-1f64, -1f64, 2f64, f64::INFINITY),
+        Err(DelaunayError::InvalidBounds)
+    );
+
+    let b = Bounds::from_corners(
+        Coord { x: -1f64, y: 0f64 },
+        Coord { x: 3f64, y: 2f64 },
+    )
+    .unwrap();
+    assert_eq!(b.width(), 4f64);
+    assert_eq!(b.height(), 2f64);
+    assert_eq!(b.center(), Coord { x: 1f64, y: 1f64 });
+    assert!(b.contains(&Coord { x: 3f64, y: 0f64 }));
+    assert!(!b.contains(&Coord { x: 3.5f64, y: 0f64 }));
+    assert_eq!(b.expand(1f64), Bounds::new(-2f64, -1f64, 4f64, 3f64));
+
+    let rect =
+        Rect::new(Coord { x: 3f64, y: 2f64 }, Coord { x: -1f64, y: 0f64 });
+    assert_eq!(Bounds::from(rect), b);
+    assert_eq!(Rect::from(b), rect);
+
+    let points = vec![
+        Coord { x: 10f64, y: 5f64 },
+        Coord { x: -10f64, y: 7f64 },
+        Coord { x: 0f64, y: -5f64 },
+    ];
+    assert_eq!(
+        Bounds::from_points(&points, 1f64),
+        Some(Bounds::new(-11f64, -6f64, 11f64, 8f64))
+    );
+    assert_eq!(Bounds::<f64>::from_points(&[], 1f64), None);
+    assert_eq!(
+        Bounds::from_points(
+            &[Coord {
+                x: 0f64,
+                y: f64::NAN
+            }],
+            1f64
+        ),
+        None
+    );
+}
+
+// Checks the halfedges are consistent, the triangles are not inverted and
+// every edge, other than a constraint, is locally delaunay.
+fn assert_constrained_delaunay(delaunay: &DelaunayStub) {
+    let t = &delaunay.delaunator.triangles;
+    let h = &delaunay.delaunator.halfedges;
+    let p = &delaunay.points;
+    for e in 0..t.len() {
+        let (a, b, c) =
+            (p[t[e]], p[t[next_halfedge(e)]], p[t[prev_halfedge(e)]]);
+        assert!((c.x - a.x) * (b.y - a.y) - (b.x - a.x) * (c.y - a.y) > 0.);
+        if h[e] == EMPTY {
+            continue;
+        }
+        assert_eq!(h[h[e]], e);
+        assert_eq!(t[h[e]], t[next_halfedge(e)]);
+        assert_eq!(delaunay.is_constrained(e), delaunay.is_constrained(h[e]));
+        if delaunay.is_constrained(e) {
+            continue;
+        }
+        // The opposite point is outside the circumcircle.
+        let d = p[t[prev_halfedge(h[e])]];
+        let (dx, dy) = (a.x - d.x, a.y - d.y);
+        let (ex, ey) = (b.x - d.x, b.y - d.y);
+        let (fx, fy) = (c.x - d.x, c.y - d.y);
+        let ap = dx * dx + dy * dy;
+        let bp = ex * ex + ey * ey;
+        let cp = fx * fx + fy * fy;
+        let det = dx * (ey * cp - bp * fy) - dy * (ex * cp - bp * fx)
+            + ap * (ex * fy - ey * fx);
+        assert!(det >= -1e-9, "edge {e} is not delaunay");
+    }
+}
+
+// Not in the original, constrained triangulations.
+#[test]
+fn with_constraints_forces_an_edge() {
+    println!("Delaunay::with_constraints() forces an edge into the mesh");
+    // The delaunay triangulation joins 2 and 3, not 0 and 1.
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 4f64, y: 0f64 },
+        Coord { x: 2f64, y: 1f64 },
+        Coord { x: 2f64, y: -1f64 },
+    ];
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let mut n = delaunay.neighbors(0).collect::<Vec<_>>();
+    n.sort_unstable();
+    assert_eq!(n, vec![2, 3]);
+    assert!(!(0..6).any(|e| delaunay.is_constrained(e)));
+
+    let delaunay: DelaunayStub =
+        Delaunay::with_constraints(&points, &[(0, 1)]).unwrap();
+    assert_eq!(delaunay.constraints(), &[(0, 1)]);
+    let mut n = delaunay.neighbors(0).collect::<Vec<_>>();
+    n.sort_unstable();
+    assert_eq!(n, vec![1, 2, 3]);
+    let mut n = delaunay.neighbors(2).collect::<Vec<_>>();
+    n.sort_unstable();
+    assert_eq!(n, vec![0, 1]);
+
+    let constrained = (0..delaunay.delaunator.halfedges.len())
+        .filter(|e| delaunay.is_constrained(*e))
+        .map(|e| {
+            let t = &delaunay.delaunator.triangles;
+            let next = if e % 3 == 2 { e - 2 } else { e + 1 };
+            (t[e].min(t[next]), t[e].max(t[next]))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(constrained, vec![(0, 1), (0, 1)]);
+
+    // The hull is unchanged and find() still works.
+    assert_eq!(delaunay.hull().count(), 4);
+    assert_eq!(delaunay.find(&Coord { x: 3.9, y: 0.1 }, None), 1);
+    assert_eq!(delaunay.find(&Coord { x: 2., y: 0.9 }, Some(0)), 2);
+
+    let voronoi = delaunay.voronoi(Some(Bounds::new(-1., -2., 5., 2.)));
+    assert_eq!(voronoi.cell_polygons().count(), 4);
+}
+
+#[test]
+fn with_constraints_crossing_many_edges() {
+    println!("Delaunay::with_constraints() flips every crossed edge");
+    let points: Vec<Coord<f64>> = (0..64)
+        .map(|i| {
+            let (x, y) = (f64::from(i % 8), f64::from(i / 8));
+            Coord {
+                x: x + 0.3 * (x * 7. + y * 3.).sin(),
+                y: y + 0.3 * (x * 5. - y * 11.).cos(),
+            }
+        })
+        .collect();
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    assert_constrained_delaunay(&delaunay);
+
+    let edges = [(0, 63), (7, 13), (57, 16)];
+    let delaunay: DelaunayStub =
+        Delaunay::with_constraints(&points, &edges).unwrap();
+    assert_constrained_delaunay(&delaunay);
+    for (u, v) in edges {
+        assert!(delaunay.neighbors(u).any(|n| n == v), "{u} {v}");
+    }
+    assert!(
+        DelaunayStub::with_constraints(&points, &[(0, 63), (56, 46)]).is_err()
+    );
+
+    // The constraints survive an update.
+    let mut delaunay = delaunay;
+    delaunay.points[27].x += 0.1;
+    delaunay.update();
+    assert_constrained_delaunay(&delaunay);
+    for (u, v) in edges {
+        assert!(delaunay.neighbors(u).any(|n| n == v), "{u} {v}");
+    }
+}
+
+#[test]
+fn with_constraints_splits_at_collinear_points() {
+    println!("Delaunay::with_constraints() splits at points on the edge");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 1f64, y: 0f64 },
+        Coord { x: 2f64, y: 0f64 },
+        Coord { x: 1f64, y: 1f64 },
+        Coord { x: 1f64, y: -1f64 },
+        Coord { x: 1f64, y: 0.5f64 },
+        Coord {
+            x: 1f64,
+            y: -0.5f64,
+        },
+    ];
+    let delaunay: DelaunayStub =
+        Delaunay::with_constraints(&points, &[(3, 4)]).unwrap();
+    assert_constrained_delaunay(&delaunay);
+    let mut n = delaunay.neighbors(1).collect::<Vec<_>>();
+    n.sort_unstable();
+    assert!(n.contains(&5) && n.contains(&6), "{n:?}");
+    let constrained = (0..delaunay.delaunator.halfedges.len())
+        .filter(|e| delaunay.is_constrained(*e))
+        .count();
+    // 3-5, 5-1, 1-6, 6-4 each with two halfedges.
+    assert_eq!(constrained, 8);
+}
+
+#[test]
+fn with_constraints_rejects_invalid_constraints() {
+    use d3_delaunay_rs::error::DelaunayError;
+
+    println!("Delaunay::with_constraints() rejects invalid constraints");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 1f64, y: 0f64 },
+        Coord { x: 1f64, y: 1f64 },
+        Coord { x: 0f64, y: 1f64 },
+        Coord { x: 0f64, y: 1f64 },
+    ];
+    let invalid = |edges: &[(usize, usize)]| {
+        DelaunayStub::with_constraints(&points, edges).unwrap_err()
+    };
+    assert_eq!(invalid(&[(0, 5)]), DelaunayError::InvalidConstraint(0));
+    assert_eq!(
+        invalid(&[(0, 2), (1, 1)]),
+        DelaunayError::InvalidConstraint(1)
+    );
+    // Point 4 is coincident with point 3.
+    let missing = if Delaunay::new(&points).inedges[4] == EMPTY {
+        (1, 4)
+    } else {
+        (1, 3)
+    };
+    assert_eq!(invalid(&[missing]), DelaunayError::InvalidConstraint(0));
+    assert_eq!(
+        invalid(&[(0, 2), (1, 3)]),
+        DelaunayError::IntersectingConstraint(1)
+    );
+}
+
+// Not in the original.
+#[test]
+fn from_polygon_removes_holes() {
+    use geo::Area;
+    use geo::Contains;
+    use geo_types::polygon;
+
+    println!("Delaunay::from_polygon() covers exactly the polygon");
+    let polygon = polygon!(
+        exterior: [
+            (x: 0f64, y: 0f64),
+            (x: 4f64, y: 0f64),
+            (x: 4f64, y: 4f64),
+            (x: 0f64, y: 4f64),
+        ],
+        interiors: [[
+            (x: 1f64, y: 1f64),
+            (x: 3f64, y: 1f64),
+            (x: 3f64, y: 3f64),
+            (x: 1f64, y: 3f64),
+        ]],
+    );
+    let delaunay = DelaunayStub::from_polygon(&polygon).unwrap();
+    assert_eq!(delaunay.points.len(), 8);
+    assert_eq!(delaunay.delaunator.triangles.len(), 3 * 8);
+
+    let triangles: Vec<_> = delaunay.triangle_polygons().collect();
+    let area: f64 = triangles.iter().map(Area::unsigned_area).sum();
+    assert!((area - 12.).abs() < 1e-12);
+    for t in &triangles {
+        let c = t.exterior().0[..3]
+            .iter()
+            .fold(Coord::zero(), |c, p| c + *p / 3.);
+        assert!(polygon.contains(&c), "{c:?}");
+    }
+
+    // The hull is the exterior ring.
+    let mut hull: Vec<usize> = delaunay.hull().collect();
+    hull.sort_unstable();
+    assert_eq!(hull, vec![0, 1, 2, 3]);
+
+    // Every edge of the rings is constrained.
+    let constrained = (0..delaunay.delaunator.triangles.len())
+        .filter(|e| delaunay.is_constrained(*e))
+        .count();
+    assert_eq!(constrained, 8);
+
+    // Points on a hole have neighbors on both sides.
+    let mut neighbors: Vec<usize> = delaunay.neighbors(4).collect();
+    neighbors.sort_unstable();
+    neighbors.dedup();
+    assert!(neighbors.contains(&5) && neighbors.contains(&7));
+    assert_eq!(delaunay.find(&Coord { x: 1.2, y: 0.9 }, None), 4);
+}
+
+// Not in the original.
+#[test]
+fn from_polygon_concave() {
+    use geo::Area;
+    use geo_types::polygon;
+
+    println!("Delaunay::from_polygon() triangulates a concave polygon");
+    let polygon = polygon![
+        (x: 0f64, y: 0f64),
+        (x: 2f64, y: 0f64),
+        (x: 2f64, y: 1f64),
+        (x: 1f64, y: 1f64),
+        (x: 1f64, y: 2f64),
+        (x: 0f64, y: 2f64),
+        (x: 0f64, y: 0f64),
+    ];
+    let delaunay = DelaunayStub::from_polygon(&polygon).unwrap();
+    assert_eq!(delaunay.triangles().count(), 4);
+    let area: f64 = delaunay
+        .triangle_polygons()
+        .map(|t| t.unsigned_area())
+        .sum();
+    assert!((area - 3.).abs() < 1e-12);
+    assert_eq!(delaunay.hull().count(), 6);
+}
+
+// Not in the original.
+#[test]
+fn locate_returns_barycentric_weights() {
+    use d3_delaunay_rs::delaunay::Location;
+
+    println!("delaunay.locate(p) returns the containing triangle");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 4f64, y: 0f64 },
+        Coord { x: 0f64, y: 4f64 },
+        Coord { x: 4f64, y: 4f64 },
+        Coord { x: 2f64, y: 1f64 },
+    ];
+    let delaunay = DelaunayStub::new(&points);
+    let triangles = &delaunay.delaunator.triangles;
+    for p in [
+        Coord { x: 1f64, y: 0.5f64 },
+        Coord { x: 3f64, y: 3f64 },
+        Coord { x: 0.5f64, y: 3f64 },
+        Coord { x: 2f64, y: 1f64 },
+    ] {
+        for hint in [None, Some(1), Some(100)] {
+            let Some(Location::Inside { triangle, weights }) =
+                delaunay.locate(&p, hint)
+            else {
+                panic!("{p:?} is inside");
+            };
+            assert!(weights.iter().all(|w| *w >= 0.));
+            assert!((weights.iter().sum::<f64>() - 1.).abs() < 1e-12);
+            let q = (0..3).fold(Coord::zero(), |q, i| {
+                q + points[triangles[3 * triangle + i]] * weights[i]
+            });
+            assert!((q.x - p.x).abs() < 1e-12 && (q.y - p.y).abs() < 1e-12);
+        }
+    }
+
+    // Beyond the right hand side of the hull.
+    let Some(Location::Outside { edge, weights }) =
+        delaunay.locate(&Coord { x: 6., y: 1. }, None)
+    else {
+        panic!("outside");
+    };
+    assert_eq!(delaunay.delaunator.halfedges[edge], EMPTY);
+    let a = points[triangles[edge]];
+    let b = points[triangles[next_halfedge(edge)]];
+    assert_eq!(a * weights[0] + b * weights[1], Coord { x: 4., y: 1. });
+
+    // Nothing to locate.
+    assert_eq!(delaunay.locate(&Coord { x: f64::NAN, y: 0. }, None), None);
+    let collinear = DelaunayStub::new(&[
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 1f64, y: 0f64 },
+        Coord { x: 2f64, y: 0f64 },
+    ]);
+    assert_eq!(collinear.locate(&Coord { x: 1., y: 0. }, None), None);
+}
+
+// Not in the original.
+#[test]
+fn locate_in_a_polygon_with_holes() {
+    use d3_delaunay_rs::delaunay::Location;
+    use geo_types::polygon;
+
+    println!("delaunay.locate(p) treats a hole as outside");
+    let polygon = polygon!(
+        exterior: [
+            (x: 0f64, y: 0f64),
+            (x: 4f64, y: 0f64),
+            (x: 4f64, y: 4f64),
+            (x: 0f64, y: 4f64),
+        ],
+        interiors: [[
+            (x: 1f64, y: 1f64),
+            (x: 3f64, y: 1f64),
+            (x: 3f64, y: 3f64),
+            (x: 1f64, y: 3f64),
+        ]],
+    );
+    let delaunay = DelaunayStub::from_polygon(&polygon).unwrap();
+    let triangles = &delaunay.delaunator.triangles;
+
+    // Starting from every triangle, across the hole.
+    for hint in 0..triangles.len() / 3 {
+        assert!(matches!(
+            delaunay.locate(&Coord { x: 3.5, y: 2. }, Some(hint)),
+            Some(Location::Inside { .. })
+        ));
+    }
+    let Some(Location::Outside { edge, weights }) =
+        delaunay.locate(&Coord { x: 2., y: 1.2 }, None)
+    else {
+        panic!("in the hole");
+    };
+    let a = delaunay.points[triangles[edge]];
+    let b = delaunay.points[triangles[next_halfedge(edge)]];
+    let q = a * weights[0] + b * weights[1];
+    assert!((q.x - 2.).abs() < 1e-12 && (q.y - 1.).abs() < 1e-12);
+}
+
+// Not in the original.
+#[test]
+fn interpolate_linear_reproduces_a_plane() {
+    println!("delaunay.interpolate_linear() is exact for a plane");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 4f64, y: 0f64 },
+        Coord { x: 0f64, y: 4f64 },
+        Coord { x: 4f64, y: 4f64 },
+        Coord { x: 1f64, y: 2f64 },
+        Coord { x: 3f64, y: 1f64 },
+    ];
+    let plane = |p: &Coord<f64>| 2. * p.x - 3. * p.y + 1.;
+    let values: Vec<f64> = points.iter().map(plane).collect();
+    let delaunay = DelaunayStub::new(&points);
+
+    for p in [
+        Coord { x: 0.5, y: 0.5 },
+        Coord { x: 2., y: 3.5 },
+        Coord { x: 3., y: 1. },
+        Coord { x: 4., y: 2. },
+    ] {
+        let v = delaunay.interpolate_linear(&values, &p).unwrap();
+        assert!((v - plane(&p)).abs() < 1e-12, "{p:?}");
+    }
+    assert_eq!(
+        delaunay.interpolate_linear(&values, &Coord { x: -1., y: 2. }),
+        None
+    );
+
+    // The grid extends beyond the hull on the right.
+    let grid = delaunay.interpolate_linear_grid(
+        &values,
+        &Bounds::new(0., 0., 5., 4.),
+        5,
+        2,
+    );
+    assert_eq!(grid.len(), 10);
+    for (k, v) in grid.iter().enumerate() {
+        let p = Coord {
+            x: (k % 5) as f64 + 0.5,
+            y: 2. * (k / 5) as f64 + 1.,
+        };
+        if k % 5 == 4 {
+            assert_eq!(*v, None);
+        } else {
+            assert!((v.unwrap() - plane(&p)).abs() < 1e-12, "{p:?}");
+        }
+    }
+}
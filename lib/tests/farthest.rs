@@ -0,0 +1,176 @@
+extern crate pretty_assertions;
+
+use geo::Area;
+use geo_types::Coord;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::bounds::Bounds;
+use d3_delaunay_rs::delaunay::Delaunay;
+use d3_delaunay_rs::farthest::Circle;
+use d3_delaunay_rs::farthest::FarthestVoronoi;
+
+type DelaunayStub = Delaunay<f64>;
+type FarthestVoronoiStub = FarthestVoronoi<f64>;
+
+// Not in the original, d3-delaunay has no farthest-point diagram.
+
+// Scattered points, without a random number generator.
+fn scatter(n: usize) -> Vec<Coord<f64>> {
+    (0..n)
+        .map(|i| {
+            let t = i as f64;
+            Coord {
+                x: 50. + 40. * (t * 12.9898).sin() * (t * 4.1414).cos(),
+                y: 50. + 40. * (t * 78.233).sin(),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn only_the_hull_has_cells() {
+    println!("the cells of a square are the opposite quadrants");
+    let points = vec![
+        Coord { x: 0., y: 0. },
+        Coord { x: 10., y: 0. },
+        Coord { x: 10., y: 10. },
+        Coord { x: 0., y: 10. },
+        Coord { x: 4., y: 6. },
+    ];
+    let voronoi: FarthestVoronoiStub = Delaunay::new(&points)
+        .farthest_voronoi(Some(Bounds::new(0., 0., 10., 10.)));
+    let areas: Vec<(usize, f64)> = voronoi
+        .cell_polygons()
+        .map(|(i, cell)| (i, cell.unsigned_area()))
+        .collect();
+    assert_eq!(areas, vec![(0, 25.), (1, 25.), (2, 25.), (3, 25.)]);
+    assert_eq!(
+        voronoi.render_cell_to_string(0),
+        "M10.00,5.00L10.00,10.00L5.00,10.00L5.00,5.00Z"
+    );
+    assert_eq!(voronoi.render_cell_to_string(4), "");
+    let mut neighbors: Vec<usize> = voronoi.neighbors(0).collect();
+    neighbors.sort_unstable();
+    assert_eq!(neighbors, vec![1, 3]);
+    assert_eq!(
+        voronoi.render_to_string(),
+        "M5.00,10.00L5.00,5.00M5.00,5.00L10.00,5.00M0.00,5.00L5.00,5.00M5.00,0.00L5.00,5.00"
+    );
+    assert_eq!(voronoi.find(&Coord { x: 1., y: 2. }), Some(2));
+    assert!(voronoi.contains(2, &Coord { x: 1., y: 2. }));
+    assert!(voronoi.contains(3, &Coord { x: 5., y: 2. }));
+    assert!(!voronoi.contains(4, &Coord { x: 1., y: 2. }));
+}
+
+#[test]
+fn find_returns_the_farthest_point() {
+    println!(
+        "find() returns the farthest point, and the cells cover the bounds"
+    );
+    let points = scatter(200);
+    let bounds = Bounds::new(-50., -50., 150., 150.);
+    let voronoi: FarthestVoronoiStub =
+        Delaunay::new(&points).farthest_voronoi(Some(bounds));
+    let total: f64 = voronoi
+        .cell_polygons()
+        .map(|(_, cell)| cell.unsigned_area())
+        .sum();
+    assert!((total - 40_000.).abs() < 1e-6);
+
+    for q in scatter(50)
+        .iter()
+        .map(|p| (*p - Coord { x: 50., y: 50. }) * 3.)
+    {
+        let max = points
+            .iter()
+            .map(|p| (p.x - q.x).hypot(p.y - q.y))
+            .fold(0., f64::max);
+        let i = voronoi.find(&q).unwrap();
+        assert_eq!((points[i].x - q.x).hypot(points[i].y - q.y), max);
+        assert!(voronoi.contains(i, &q));
+    }
+    assert_eq!(voronoi.find(&Coord { x: f64::NAN, y: 0. }), None);
+}
+
+#[test]
+fn enclosing_circle_is_the_smallest() {
+    println!(
+        "enclosing_circle() returns the smallest circle enclosing the points"
+    );
+    let points = scatter(300);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let circle = delaunay.enclosing_circle().unwrap();
+    for p in &points {
+        assert!(
+            (p.x - circle.center.x).hypot(p.y - circle.center.y)
+                <= circle.radius * (1. + 1e-12)
+        );
+    }
+
+    // Every circle through two or three points of the hull which encloses
+    // all the points is at least as large.
+    let hull: Vec<Coord<f64>> = delaunay.hull().map(|i| points[i]).collect();
+    let encloses = |c: &Coord<f64>, r: f64| {
+        points
+            .iter()
+            .all(|p| (p.x - c.x).hypot(p.y - c.y) <= r * (1. + 1e-9))
+    };
+    for (i, a) in hull.iter().enumerate() {
+        for b in &hull[i + 1..] {
+            let c = (*a + *b) / 2.;
+            let r = (a.x - b.x).hypot(a.y - b.y) / 2.;
+            if encloses(&c, r) {
+                assert!(circle.radius <= r * (1. + 1e-9));
+            }
+        }
+    }
+}
+
+#[test]
+fn enclosing_circle_degenerate_cases() {
+    println!("enclosing_circle() handles collinear and repeated points");
+    let delaunay: DelaunayStub = Delaunay::new(&[]);
+    assert_eq!(delaunay.enclosing_circle(), None);
+
+    let delaunay: DelaunayStub =
+        Delaunay::new(&[Coord { x: 1., y: 2. }, Coord { x: 1., y: 2. }]);
+    assert_eq!(
+        delaunay.enclosing_circle(),
+        Some(Circle {
+            center: Coord { x: 1., y: 2. },
+            radius: 0.
+        })
+    );
+
+    let points: Vec<Coord<f64>> = (0..5)
+        .map(|i| Coord {
+            x: f64::from(i),
+            y: f64::from(2 * i),
+        })
+        .collect();
+    let circle = Delaunay::new(&points).enclosing_circle().unwrap();
+    assert!((circle.center.x - 2.).abs() < 1e-6);
+    assert!((circle.center.y - 4.).abs() < 1e-6);
+    assert!((circle.radius - 20_f64.sqrt()).abs() < 1e-6);
+
+    // An obtuse triangle, the longest side is a diameter.
+    let circle = Delaunay::new(&[
+        Coord { x: 0., y: 0. },
+        Coord { x: 4., y: 0. },
+        Coord { x: 2., y: 1. },
+    ])
+    .enclosing_circle()
+    .unwrap();
+    assert_eq!(
+        circle,
+        Circle {
+            center: Coord { x: 2., y: 0. },
+            radius: 2.
+        }
+    );
+    assert!(circle.contains(&Coord { x: 2., y: 1. }));
+    assert_eq!(
+        circle.render_to_string(),
+        "M4.00,0.00A2.00,2.00,0,1,1,0.00,0.00A2.00,2.00,0,1,1,4.00,0.00"
+    );
+}
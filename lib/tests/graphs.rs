@@ -0,0 +1,175 @@
+extern crate pretty_assertions;
+
+use geo_types::Coord;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::delaunay::Delaunay;
+use d3_delaunay_rs::graphs::Graph;
+
+type DelaunayStub = Delaunay<f64>;
+
+// Not in the original, d3-delaunay has no proximity graphs.
+
+// Scattered points, without a random number generator.
+fn scatter(n: usize) -> Vec<Coord<f64>> {
+    (0..n)
+        .map(|i| {
+            let t = i as f64;
+            Coord {
+                x: 50. + 40. * (t * 12.9898).sin() * (t * 4.1414).cos(),
+                y: 50. + 40. * (t * 78.233).sin(),
+            }
+        })
+        .collect()
+}
+
+fn dist2(a: &Coord<f64>, b: &Coord<f64>) -> f64 {
+    (a.x - b.x).powi(2) + (a.y - b.y).powi(2)
+}
+
+// Every pair of points passing the test, compared with every other point.
+fn brute_force(
+    points: &[Coord<f64>],
+    blocks: impl Fn(&Coord<f64>, &Coord<f64>, &Coord<f64>) -> bool,
+) -> Vec<(usize, usize)> {
+    let mut edges = vec![];
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let (p, q) = (&points[i], &points[j]);
+            let blocked = points
+                .iter()
+                .enumerate()
+                .any(|(k, r)| k != i && k != j && blocks(p, q, r));
+            if !blocked {
+                edges.push((i, j));
+            }
+        }
+    }
+    edges
+}
+
+#[test]
+fn gabriel_graph() {
+    println!("gabriel() matches the definition");
+    let points = scatter(150);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let expected = brute_force(&points, |p, q, r| {
+        dist2(p, r) + dist2(q, r) <= dist2(p, q)
+    });
+    assert_eq!(Graph::gabriel(&delaunay).edges, expected);
+}
+
+#[test]
+fn relative_neighborhood_graph() {
+    println!("relative_neighborhood() matches the definition");
+    let points = scatter(150);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let expected = brute_force(&points, |p, q, r| {
+        let d = dist2(p, q);
+        dist2(p, r) < d && dist2(q, r) < d
+    });
+    assert_eq!(Graph::relative_neighborhood(&delaunay).edges, expected);
+}
+
+#[test]
+fn graphs_are_nested() {
+    println!("each graph is a subgraph of the next");
+    let points = scatter(300);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let graphs = [
+        Graph::minimum_spanning_tree(&delaunay),
+        Graph::relative_neighborhood(&delaunay),
+        Graph::urquhart(&delaunay),
+        Graph::gabriel(&delaunay),
+        Graph::delaunay(&delaunay),
+    ];
+    for pair in graphs.windows(2) {
+        assert!(pair[0].edges.len() < pair[1].edges.len());
+        for edge in &pair[0].edges {
+            assert!(pair[1].edges.binary_search(edge).is_ok(), "{edge:?}");
+        }
+    }
+    assert_eq!(graphs[0].edges.len(), points.len() - 1);
+    assert_eq!(
+        graphs[4].edges.len(),
+        3 * points.len() - 3 - delaunay.hull().count()
+    );
+}
+
+#[test]
+fn minimum_spanning_tree_is_shortest() {
+    println!("minimum_spanning_tree() matches Prim's algorithm on every pair");
+    let points = scatter(200);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let tree = Graph::minimum_spanning_tree(&delaunay);
+    let length = |edges: &[(usize, usize)]| -> f64 {
+        edges
+            .iter()
+            .map(|(i, j)| dist2(&points[*i], &points[*j]).sqrt())
+            .sum()
+    };
+
+    let mut best = vec![f64::INFINITY; points.len()];
+    let mut done = vec![false; points.len()];
+    let mut total = 0.;
+    best[0] = 0.;
+    for _ in 0..points.len() {
+        let i = (0..points.len())
+            .filter(|i| !done[*i])
+            .min_by(|a, b| best[*a].total_cmp(&best[*b]))
+            .unwrap();
+        done[i] = true;
+        total += best[i].sqrt();
+        for j in 0..points.len() {
+            best[j] = best[j].min(dist2(&points[i], &points[j]));
+        }
+    }
+    assert!((length(&tree.edges) - total).abs() < 1e-9);
+}
+
+#[test]
+fn graphs_of_collinear_points() {
+    println!("collinear points are joined along the line");
+    let points = vec![
+        Coord { x: 2., y: 2. },
+        Coord { x: 0., y: 0. },
+        Coord { x: 3., y: 3. },
+        Coord { x: 1., y: 1. },
+        Coord { x: 1., y: 1. },
+    ];
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    for graph in [
+        Graph::delaunay(&delaunay),
+        Graph::gabriel(&delaunay),
+        Graph::relative_neighborhood(&delaunay),
+        Graph::urquhart(&delaunay),
+        Graph::minimum_spanning_tree(&delaunay),
+    ] {
+        assert_eq!(graph.edges, vec![(0, 2), (0, 3), (1, 3)]);
+    }
+    assert_eq!(
+        Graph::gabriel(&delaunay).render_to_string(),
+        "M2.00,2.00L3.00,3.00M2.00,2.00L1.00,1.00M0.00,0.00L1.00,1.00"
+    );
+
+    let delaunay: DelaunayStub = Delaunay::new(&points[..1]);
+    assert!(Graph::delaunay(&delaunay).edges.is_empty());
+}
+
+#[test]
+fn urquhart_graph_of_a_square() {
+    println!("urquhart() removes the diagonal of a square");
+    let points = vec![
+        Coord { x: 0., y: 0. },
+        Coord { x: 1., y: 0. },
+        Coord { x: 1., y: 1. },
+        Coord { x: 0., y: 1. },
+    ];
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    assert_eq!(Graph::delaunay(&delaunay).edges.len(), 5);
+    let square = vec![(0, 1), (0, 3), (1, 2), (2, 3)];
+    assert_eq!(Graph::urquhart(&delaunay).edges, square);
+    assert_eq!(Graph::gabriel(&delaunay).edges, square);
+    assert_eq!(Graph::relative_neighborhood(&delaunay).edges, square);
+    assert_eq!(Graph::minimum_spanning_tree(&delaunay).edges.len(), 3);
+}
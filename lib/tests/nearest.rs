@@ -0,0 +1,122 @@
+extern crate pretty_assertions;
+
+use geo_types::Coord;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::delaunay::Delaunay;
+
+type DelaunayStub = Delaunay<f64>;
+
+// Not in the original, d3-delaunay only finds the nearest point.
+
+// Scattered points, without a random number generator.
+fn scatter(n: usize) -> Vec<Coord<f64>> {
+    (0..n)
+        .map(|i| {
+            let t = i as f64;
+            Coord {
+                x: 50. + 40. * (t * 12.9898).sin() * (t * 4.1414).cos(),
+                y: 50. + 40. * (t * 78.233).sin(),
+            }
+        })
+        .collect()
+}
+
+fn dist2(a: &Coord<f64>, b: &Coord<f64>) -> f64 {
+    (a.x - b.x).powi(2) + (a.y - b.y).powi(2)
+}
+
+// Every point sorted by distance from p, then by index.
+fn brute_force(points: &[Coord<f64>], p: &Coord<f64>) -> Vec<usize> {
+    let mut all: Vec<usize> = (0..points.len()).collect();
+    all.sort_by(|a, b| {
+        dist2(&points[*a], p)
+            .partial_cmp(&dist2(&points[*b], p))
+            .unwrap()
+            .then(a.cmp(b))
+    });
+    all
+}
+
+#[test]
+fn k_nearest_matches_brute_force() {
+    println!("k_nearest() returns the k nearest points in order");
+    let points = scatter(300);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    for (n, p) in scatter(50).iter().enumerate() {
+        // Queries away from the points, some outside the hull.
+        let p = Coord {
+            x: 1.5 * p.x - 25. + 0.123,
+            y: 1.5 * p.y - 25. + 0.456,
+        };
+        let expected = brute_force(&points, &p);
+        for k in [1, 5, 40] {
+            let hint = Some(n * 5);
+            assert_eq!(delaunay.k_nearest(&p, k, hint), expected[..k]);
+        }
+        assert_eq!(delaunay.k_nearest(&p, 1, None), [delaunay.find(&p, None)]);
+    }
+    let p = Coord { x: 50., y: 50. };
+    assert_eq!(delaunay.k_nearest(&p, 1000, None), brute_force(&points, &p));
+}
+
+#[test]
+fn within_radius_matches_brute_force() {
+    println!("within_radius() returns the points within r in order");
+    let points = scatter(300);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    for p in scatter(50).iter().skip(1) {
+        let p = Coord {
+            x: p.x + 0.5,
+            y: p.y - 0.5,
+        };
+        for r in [0.1, 5., 20.] {
+            let expected: Vec<usize> = brute_force(&points, &p)
+                .into_iter()
+                .take_while(|i| dist2(&points[*i], &p) <= r * r)
+                .collect();
+            assert_eq!(delaunay.within_radius(&p, r, None), expected);
+        }
+    }
+}
+
+#[test]
+fn nearest_ties() {
+    println!("k_nearest() orders points at the same distance by index");
+    let points = vec![
+        Coord { x: 1., y: 0. },
+        Coord { x: 0., y: 1. },
+        Coord { x: -1., y: 0. },
+        Coord { x: 0., y: -1. },
+        Coord { x: 3., y: 3. },
+    ];
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let origin = Coord { x: 0., y: 0. };
+    assert_eq!(delaunay.k_nearest(&origin, 2, Some(4)), vec![0, 1]);
+    assert_eq!(delaunay.k_nearest(&origin, 3, None), vec![0, 1, 2]);
+    assert_eq!(delaunay.within_radius(&origin, 1., None), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn nearest_degenerate() {
+    println!("k_nearest() and within_radius() of degenerate input");
+    let collinear = vec![
+        Coord { x: 0., y: 0. },
+        Coord { x: 3., y: 0. },
+        Coord { x: 1., y: 0. },
+        Coord { x: 2., y: 0. },
+    ];
+    let delaunay: DelaunayStub = Delaunay::new(&collinear);
+    let p = Coord { x: 2.9, y: 1. };
+    assert_eq!(delaunay.k_nearest(&p, 3, None), vec![1, 3, 2]);
+    assert_eq!(delaunay.within_radius(&p, 2., None), vec![1, 3]);
+
+    let nan = Coord { x: f64::NAN, y: 0. };
+    assert!(delaunay.k_nearest(&nan, 3, None).is_empty());
+    assert!(delaunay.within_radius(&nan, 3., None).is_empty());
+    assert!(delaunay.k_nearest(&p, 0, None).is_empty());
+    assert!(delaunay.within_radius(&p, -2., None).is_empty());
+
+    let delaunay: DelaunayStub = Delaunay::new(&[]);
+    assert!(delaunay.k_nearest(&p, 3, None).is_empty());
+}
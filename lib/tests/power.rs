@@ -0,0 +1,218 @@
+extern crate pretty_assertions;
+
+use geo::Area;
+use geo_types::polygon;
+use geo_types::Coord;
+use geo_types::Polygon;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::bounds::Bounds;
+use d3_delaunay_rs::delaunay::Delaunay;
+use d3_delaunay_rs::error::DelaunayError;
+use d3_delaunay_rs::power::PowerDiagram;
+use d3_delaunay_rs::voronoi::Voronoi;
+
+type PowerDiagramStub = PowerDiagram<f64>;
+type VoronoiStub = Voronoi<f64>;
+
+// Not in the original, the power module has no javascript equivalent.
+
+// The vertices of a closed ring, without the closing point, starting
+// from the lowest.
+fn ring(closed: &[Coord<f64>]) -> Vec<Coord<f64>> {
+    let mut ring = closed[..closed.len() - 1].to_vec();
+    let lowest = (0..ring.len())
+        .min_by(|a, b| {
+            (ring[*a].y, ring[*a].x)
+                .partial_cmp(&(ring[*b].y, ring[*b].x))
+                .unwrap()
+        })
+        .unwrap();
+    ring.rotate_left(lowest);
+    ring
+}
+
+#[test]
+fn equal_weights_give_the_voronoi_diagram() {
+    println!("with equal weights the cells are those of the voronoi diagram");
+    let points = vec![
+        Coord { x: 12., y: 31. },
+        Coord { x: 78., y: 14. },
+        Coord { x: 41., y: 66. },
+        Coord { x: 85., y: 83. },
+        Coord { x: 50., y: 40. },
+    ];
+    let bounds = Bounds::new(0., 0., 100., 100.);
+    let power: PowerDiagramStub =
+        PowerDiagram::new(&points, &[3.; 5], Some(bounds));
+    let voronoi: VoronoiStub = Delaunay::new(&points).voronoi(Some(bounds));
+
+    let mut total = 0.;
+    for (i, cell) in power.cell_polygons() {
+        let found = ring(&power.cell_polygon(i));
+        let expected = ring(&voronoi.cell_polygon(i));
+        assert_eq!(found.len(), expected.len());
+        for (a, b) in found.iter().zip(&expected) {
+            assert!((a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9);
+        }
+        total += cell.unsigned_area();
+
+        let mut neighbors: Vec<usize> = power.neighbors(i).collect();
+        let mut expected: Vec<usize> = voronoi.neighbors(i).collect();
+        neighbors.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(neighbors, expected);
+    }
+    assert!((total - 10_000.).abs() < 1e-9);
+}
+
+#[test]
+fn weights_move_the_boundary() {
+    println!("a larger weight moves the boundary away from the site");
+    let points = vec![Coord { x: 1., y: 2. }, Coord { x: 3., y: 2. }];
+    let power: PowerDiagramStub = PowerDiagram::new(
+        &points,
+        &[0., 4.],
+        Some(Bounds::new(0., 0., 4., 4.)),
+    );
+    let areas: Vec<f64> = power
+        .cell_polygons()
+        .map(|(_, cell)| cell.unsigned_area())
+        .collect();
+    assert_eq!(areas, vec![4., 12.]);
+    assert_eq!(power.render_to_string(), "M1.00,0.00L1.00,4.00");
+    assert_eq!(power.find(&Coord { x: 1.5, y: 2. }), Some(1));
+    assert_eq!(power.find(&Coord { x: 0.5, y: 2. }), Some(0));
+    assert!(power.contains(0, &Coord { x: 1., y: 3. }));
+    assert!(power.contains(1, &Coord { x: 1., y: 3. }));
+}
+
+#[test]
+fn a_site_may_have_an_empty_cell() {
+    println!("a site inside the circle of a heavier site has no cell");
+    let points = vec![Coord { x: 50., y: 50. }, Coord { x: 55., y: 50. }];
+    let power: PowerDiagramStub = PowerDiagram::new(
+        &points,
+        &[10_000., 0.],
+        Some(Bounds::new(0., 0., 100., 100.)),
+    );
+    assert!(power.cell_polygon(1).is_empty());
+    assert_eq!(power.render_cell_to_string(1), "");
+    assert_eq!(power.neighbors(0).count(), 0);
+    assert_eq!(power.cell_polygons().count(), 1);
+    assert_eq!(power.find(&Coord { x: 55., y: 50. }), Some(0));
+    assert_eq!(
+        power.render_cell_to_string(0),
+        "M100.00,0.00L100.00,100.00L0.00,100.00L0.00,0.00Z"
+    );
+}
+
+#[test]
+fn update_recomputes_the_cells() {
+    println!("update() recomputes the cells after the weights change");
+    let points = vec![Coord { x: 1., y: 2. }, Coord { x: 3., y: 2. }];
+    let mut power: PowerDiagramStub = PowerDiagram::new(
+        &points,
+        &[0., 0.],
+        Some(Bounds::new(0., 0., 4., 4.)),
+    );
+    assert_eq!(power.render_to_string(), "M2.00,0.00L2.00,4.00");
+    power.weights[0] = 4.;
+    power.update();
+    assert_eq!(power.render_to_string(), "M3.00,0.00L3.00,4.00");
+}
+
+#[test]
+fn try_new_rejects_invalid_input() {
+    println!("try_new() reports invalid input");
+    let points = vec![Coord { x: 1., y: 2. }, Coord { x: 3., y: 2. }];
+    assert_eq!(
+        PowerDiagramStub::try_new(&points, &[0.], None).unwrap_err(),
+        DelaunayError::WeightCount(1)
+    );
+    assert_eq!(
+        PowerDiagramStub::try_new(&points, &[0., f64::NAN], None).unwrap_err(),
+        DelaunayError::NonFiniteWeight(1)
+    );
+    assert_eq!(
+        PowerDiagramStub::try_new(
+            &[Coord {
+                x: f64::INFINITY,
+                y: 0.
+            }],
+            &[0.],
+            None
+        )
+        .unwrap_err(),
+        DelaunayError::NonFiniteCoordinate(0)
+    );
+    assert_eq!(
+        PowerDiagramStub::try_new(
+            &points,
+            &[0., 0.],
+            Some(Bounds {
+                xmin: 4.,
+                ymin: 0.,
+                xmax: 0.,
+                ymax: 4.
+            })
+        )
+        .unwrap_err(),
+        DelaunayError::InvalidBounds
+    );
+}
+
+#[test]
+fn cells_are_clipped_to_a_region() {
+    println!("try_with_region() clips the cells to a convex region");
+    let region: Polygon<f64> = polygon![
+        (x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)
+    ];
+    let points = vec![Coord { x: 1., y: 1. }, Coord { x: 1., y: 3. }];
+    let power =
+        PowerDiagramStub::try_with_region(&points, &[0., 0.], &region).unwrap();
+    assert_eq!(power.bounds, Bounds::new(0., 0., 4., 4.));
+    assert_eq!(
+        power.render_bounds_to_string(),
+        "M0.00,0.00L4.00,0.00L0.00,4.00Z"
+    );
+    assert_eq!(power.render_to_string(), "M2.00,2.00L0.00,2.00");
+    let areas: Vec<f64> = power
+        .cell_polygons()
+        .map(|(_, cell)| cell.unsigned_area())
+        .collect();
+    assert_eq!(areas, vec![6., 2.]);
+    assert_eq!(
+        PowerDiagramStub::try_with_region(
+            &points,
+            &[0., 0.],
+            &Polygon::new(vec![(0., 0.), (1., 1.), (0., 0.)].into(), vec![])
+        )
+        .unwrap_err(),
+        DelaunayError::InvalidRegion
+    );
+
+    // Clipping to half-planes needs a convex region.
+    let concave: Polygon<f64> = polygon![
+        (x: 0., y: 0.), (x: 4., y: 0.), (x: 1., y: 1.), (x: 0., y: 4.)
+    ];
+    let star: Polygon<f64> = polygon![
+        (x: 0., y: 0.), (x: 2., y: 4.), (x: 4., y: 0.), (x: -1., y: 2.5),
+        (x: 5., y: 2.5)
+    ];
+    for region in [concave, star] {
+        assert_eq!(
+            PowerDiagramStub::try_with_region(&points, &[0., 0.], &region)
+                .unwrap_err(),
+            DelaunayError::InvalidRegion
+        );
+    }
+    // Either orientation, and a straight vertex, are accepted.
+    let clockwise: Polygon<f64> = polygon![
+        (x: 0., y: 0.), (x: 0., y: 2.), (x: 0., y: 4.), (x: 4., y: 0.)
+    ];
+    assert!(
+        PowerDiagramStub::try_with_region(&points, &[0., 0.], &clockwise)
+            .is_ok()
+    );
+}
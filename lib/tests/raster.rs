@@ -0,0 +1,111 @@
+extern crate pretty_assertions;
+
+use delaunator::EMPTY;
+use geo_types::Coord;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::delaunay::Delaunay;
+
+type DelaunayStub = Delaunay<f64>;
+
+// Not in the original, d3-delaunay searches for one point at a time.
+
+// Scattered points, without a random number generator.
+fn scatter(n: usize) -> Vec<Coord<f64>> {
+    (0..n)
+        .map(|i| {
+            let t = i as f64;
+            Coord {
+                x: 50. + 40. * (t * 12.9898).sin() * (t * 4.1414).cos(),
+                y: 50. + 40. * (t * 78.233).sin(),
+            }
+        })
+        .collect()
+}
+
+fn dist2(a: &Coord<f64>, b: &Coord<f64>) -> f64 {
+    (a.x - b.x).powi(2) + (a.y - b.y).powi(2)
+}
+
+// The distance from p to the nearest point.
+fn nearest(points: &[Coord<f64>], p: &Coord<f64>) -> f64 {
+    points
+        .iter()
+        .map(|q| dist2(p, q))
+        .fold(f64::INFINITY, f64::min)
+}
+
+// Maps the pixels of a 50 * 40 image over the plane of the points.
+fn transform(p: &Coord<f64>) -> Coord<f64> {
+    Coord {
+        x: 2. * p.x,
+        y: 100. - 2.5 * p.y,
+    }
+}
+
+#[test]
+fn find_many_matches_find() {
+    println!("find_many() finds the nearest point to each query");
+    let points = scatter(200);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let queries: Vec<Coord<f64>> = scatter(500)
+        .iter()
+        .map(|p| Coord {
+            x: 1.2 * p.x - 10.,
+            y: p.y + 0.5,
+        })
+        .collect();
+    let found = delaunay.find_many(&queries);
+    assert_eq!(found.len(), queries.len());
+    for (p, i) in queries.iter().zip(found) {
+        assert_eq!(dist2(&points[i], p), nearest(&points, p));
+    }
+    assert!(delaunay.find_many(&[]).is_empty());
+}
+
+#[test]
+fn nearest_site_raster_labels_pixels() {
+    println!("nearest_site_raster() labels each pixel with its nearest point");
+    let points = scatter(200);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let labels = delaunay.nearest_site_raster(50, 40, transform);
+    assert_eq!(labels.len(), 50 * 40);
+    for (index, i) in labels.iter().enumerate() {
+        let p = transform(&Coord {
+            x: (index % 50) as f64 + 0.5,
+            y: (index / 50) as f64 + 0.5,
+        });
+        assert_eq!(dist2(&points[*i], &p), nearest(&points, &p));
+    }
+    assert!(delaunay.nearest_site_raster(0, 40, transform).is_empty());
+    assert!(delaunay.nearest_site_raster(50, 0, transform).is_empty());
+}
+
+#[test]
+fn nearest_site_raster_without_points() {
+    println!("nearest_site_raster() without points is EMPTY everywhere");
+    let delaunay: DelaunayStub = Delaunay::new(&[]);
+    assert_eq!(delaunay.nearest_site_raster(3, 2, |p| *p), vec![EMPTY; 6]);
+    assert_eq!(delaunay.find_many(&[Coord { x: 0., y: 0. }]), vec![EMPTY]);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_matches_sequential() {
+    println!("par_find_many() and par_nearest_site_raster() match");
+    let points = scatter(200);
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let queries = scatter(5000);
+    let found = delaunay.par_find_many(&queries);
+    for (p, i) in queries.iter().zip(found) {
+        assert_eq!(dist2(&points[i], p), nearest(&points, p));
+    }
+    let labels = delaunay.par_nearest_site_raster(50, 40, transform);
+    for (index, i) in labels.iter().enumerate() {
+        let p = transform(&Coord {
+            x: (index % 50) as f64 + 0.5,
+            y: (index / 50) as f64 + 0.5,
+        });
+        assert_eq!(dist2(&points[*i], &p), nearest(&points, &p));
+    }
+}
@@ -0,0 +1,156 @@
+extern crate pretty_assertions;
+
+use geo::Area;
+use geo::Contains;
+use geo::Intersects;
+use geo_types::polygon;
+use geo_types::Coord;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::delaunay::Delaunay;
+use d3_delaunay_rs::quality::Quality;
+
+type DelaunayStub = Delaunay<f64>;
+
+// Not in the original, refinement has no javascript equivalent.
+
+fn smallest_angle(delaunay: &DelaunayStub) -> f64 {
+    let angle = |p: Coord<f64>, q: Coord<f64>, r: Coord<f64>| {
+        let (u, v) = (q - p, r - p);
+        (u.x * v.y - u.y * v.x)
+            .abs()
+            .atan2(u.x * v.x + u.y * v.y)
+            .to_degrees()
+    };
+    delaunay
+        .triangles()
+        .map(|t| {
+            let [a, b, c] = t.to_array();
+            angle(a, b, c).min(angle(b, c, a)).min(angle(c, a, b))
+        })
+        .fold(180., f64::min)
+}
+
+#[test]
+fn refines_to_a_minimum_angle() {
+    println!("refine() adds points until no angle is too small");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 10f64, y: 0f64 },
+        Coord { x: 5f64, y: 0.5f64 },
+        Coord { x: 4f64, y: 6f64 },
+        Coord { x: 9f64, y: 4f64 },
+        Coord { x: 1f64, y: 3f64 },
+    ];
+    let mut delaunay = DelaunayStub::new(&points);
+    assert!(smallest_angle(&delaunay) < 20.);
+    let area: f64 = delaunay.hull_polygon().unsigned_area();
+
+    let added = delaunay.refine(&Quality::default()).unwrap();
+    assert!(added > 0);
+    assert_eq!(delaunay.points.len(), points.len() + added);
+    // The input points are untouched.
+    assert_eq!(delaunay.points[..points.len()], points[..]);
+    assert!(smallest_angle(&delaunay) >= 20.);
+    // Points are only added within the hull.
+    assert!((delaunay.hull_polygon().unsigned_area() - area).abs() < 1e-9);
+
+    // There is nothing left to do.
+    assert_eq!(delaunay.refine(&Quality::default()).unwrap(), 0);
+}
+
+#[test]
+fn refines_to_a_maximum_area() {
+    println!("refine() adds points until no triangle is too large");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 4f64, y: 0f64 },
+        Coord { x: 4f64, y: 4f64 },
+        Coord { x: 0f64, y: 4f64 },
+    ];
+    let mut delaunay = DelaunayStub::new(&points);
+    let quality = Quality {
+        min_angle: 30.,
+        max_area: Some(0.5),
+        ..Quality::default()
+    };
+    delaunay.refine(&quality).unwrap();
+    assert!(delaunay
+        .triangle_polygons()
+        .all(|t| t.unsigned_area() <= 0.5));
+    assert!(smallest_angle(&delaunay) >= 30.);
+    assert!(delaunay.hull().count() > 4);
+}
+
+#[test]
+fn refine_respects_holes() {
+    println!("refine() keeps the points of a polygon out of its holes");
+    let polygon = polygon!(
+        exterior: [
+            (x: 0f64, y: 0f64),
+            (x: 4f64, y: 0f64),
+            (x: 4f64, y: 4f64),
+            (x: 0f64, y: 4f64),
+        ],
+        interiors: [[
+            (x: 1f64, y: 1f64),
+            (x: 3f64, y: 1f64),
+            (x: 3f64, y: 3f64),
+            (x: 1f64, y: 3f64),
+        ]],
+    );
+    let mut delaunay = DelaunayStub::from_polygon(&polygon).unwrap();
+    let quality = Quality {
+        min_angle: 25.,
+        max_area: Some(0.1),
+        ..Quality::default()
+    };
+    let added = delaunay.refine(&quality).unwrap();
+    assert!(added > 0);
+
+    let area: f64 = delaunay
+        .triangle_polygons()
+        .map(|t| t.unsigned_area())
+        .sum();
+    assert!((area - 12.).abs() < 1e-9);
+    for p in &delaunay.points {
+        assert!(polygon.intersects(p), "{p:?}");
+    }
+    for t in delaunay.triangle_polygons() {
+        let c = t.exterior().0[..3]
+            .iter()
+            .fold(Coord::zero(), |c, p| c + *p / 3.);
+        assert!(polygon.contains(&c), "{c:?}");
+    }
+    assert!(smallest_angle(&delaunay) >= 25.);
+
+    // The split rings are kept when the points move.
+    delaunay.update();
+    let area: f64 = delaunay
+        .triangle_polygons()
+        .map(|t| t.unsigned_area())
+        .sum();
+    assert!((area - 12.).abs() < 1e-9);
+}
+
+#[test]
+fn refine_stops_at_the_maximum_points() {
+    println!("refine() adds no more than the maximum number of points");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 100f64, y: 0f64 },
+        Coord { x: 50f64, y: 1f64 },
+    ];
+    let mut delaunay = DelaunayStub::new(&points);
+    let quality = Quality {
+        max_area: Some(1.),
+        max_points: 5,
+        ..Quality::default()
+    };
+    assert_eq!(delaunay.refine(&quality).unwrap(), 5);
+    assert_eq!(delaunay.points.len(), 8);
+
+    // Nothing to refine.
+    let mut delaunay = DelaunayStub::new(&points[..2]);
+    assert_eq!(delaunay.refine(&quality).unwrap(), 0);
+}
@@ -0,0 +1,156 @@
+extern crate pretty_assertions;
+
+use geo_types::Coord;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::bounds::Bounds;
+use d3_delaunay_rs::delaunay::Delaunay;
+use d3_delaunay_rs::relax::Density;
+use d3_delaunay_rs::relax::Relax;
+use d3_delaunay_rs::voronoi::Voronoi;
+
+type VoronoiStub = Voronoi<f64>;
+
+// Not in the original, the relax module has no javascript equivalent.
+
+#[test]
+fn relaxes_to_a_centroidal_tessellation() {
+    println!("relax() moves points onto the centroids of their cells");
+    let points = vec![
+        Coord { x: 20., y: 30. },
+        Coord { x: 80., y: 20. },
+        Coord { x: 30., y: 70. },
+        Coord { x: 70., y: 80. },
+    ];
+    let mut voronoi: VoronoiStub =
+        Delaunay::new(&points).voronoi(Some(Bounds::new(0., 0., 100., 100.)));
+
+    let relax = Relax::default();
+    let convergence = relax.run(&mut voronoi);
+    assert!(convergence.converged);
+    assert!(convergence.iterations < relax.iterations);
+    assert!(convergence.displacement <= relax.threshold);
+
+    let expected = [(25., 25.), (75., 25.), (25., 75.), (75., 75.)];
+    for (p, (x, y)) in voronoi.delaunay.points.iter().zip(expected) {
+        assert!((p.x - x).abs() < 1e-3, "{p:?}");
+        assert!((p.y - y).abs() < 1e-3, "{p:?}");
+    }
+
+    // The diagram has been updated to match the points.
+    let centroids = relax.centroids(&voronoi);
+    for (p, c) in voronoi.delaunay.points.iter().zip(centroids) {
+        assert!((p.x - c.x).hypot(p.y - c.y) < 1e-5);
+    }
+}
+
+#[test]
+fn stops_after_the_maximum_iterations() {
+    println!("relax() stops after the maximum number of iterations");
+    let points = vec![
+        Coord { x: 1., y: 1. },
+        Coord { x: 2., y: 1. },
+        Coord { x: 1., y: 2. },
+    ];
+    let mut voronoi: VoronoiStub =
+        Delaunay::new(&points).voronoi(Some(Bounds::new(0., 0., 100., 100.)));
+    let relax = Relax {
+        iterations: 2,
+        threshold: 0.,
+        ..Relax::default()
+    };
+    let convergence = relax.run(&mut voronoi);
+    assert_eq!(convergence.iterations, 2);
+    assert!(!convergence.converged);
+    assert!(convergence.displacement > 0.);
+}
+
+#[test]
+fn uniform_centroid_of_the_bounds() {
+    println!("the centroid of a single point is the center of the bounds");
+    let points = vec![Coord { x: 0.5, y: 0.5 }];
+    let voronoi: VoronoiStub =
+        Delaunay::new(&points).voronoi(Some(Bounds::new(0., 0., 2., 4.)));
+    let relax = Relax::default();
+    assert_eq!(relax.centroids(&voronoi), vec![Coord { x: 1., y: 2. }]);
+}
+
+#[test]
+fn density_function() {
+    println!("a density function pulls the centroid towards heavy regions");
+    let points = vec![Coord { x: 0.5, y: 0.5 }];
+    let mut voronoi: VoronoiStub =
+        Delaunay::new(&points).voronoi(Some(Bounds::new(0., 0., 1., 1.)));
+    let density = |p: &Coord<f64>| p.x;
+    let relax = Relax {
+        density: Density::Function(&density),
+        iterations: 1,
+        ..Relax::default()
+    };
+    relax.run(&mut voronoi);
+    let p = voronoi.delaunay.points[0];
+    assert!((p.x - 2. / 3.).abs() < 1e-12);
+    assert!((p.y - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn density_raster() {
+    println!("a raster assigns each pixel to the nearest point");
+    let data = [1., 1., 0., 0., 0., 0., 0., 4.];
+    let relax = Relax {
+        density: Density::Raster {
+            data: &data,
+            width: 4,
+            height: 2,
+        },
+        ..Relax::default()
+    };
+
+    let points = vec![Coord { x: 0.5, y: 0.5 }];
+    let voronoi: VoronoiStub =
+        Delaunay::new(&points).voronoi(Some(Bounds::new(0., 0., 4., 2.)));
+    // (1 * (0.5, 0.5) + 1 * (1.5, 0.5) + 4 * (3.5, 1.5)) / 6
+    assert_eq!(
+        relax.centroids(&voronoi),
+        vec![Coord {
+            x: 16. / 6.,
+            y: 7. / 6.
+        }]
+    );
+
+    // A point with no weight in its cell stays put.
+    let points = vec![
+        Coord { x: 0.5, y: 0.5 },
+        Coord { x: 2.5, y: 0.5 },
+        Coord { x: 3.5, y: 1.5 },
+    ];
+    let voronoi: VoronoiStub =
+        Delaunay::new(&points).voronoi(Some(Bounds::new(0., 0., 4., 2.)));
+    assert_eq!(
+        relax.centroids(&voronoi),
+        vec![
+            Coord { x: 1., y: 0.5 },
+            Coord { x: 2.5, y: 0.5 },
+            Coord { x: 3.5, y: 1.5 },
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "A raster must hold width * height values.")]
+fn density_raster_checks_the_length() {
+    println!("a raster with too few values is rejected");
+    let data = [1., 1., 0.];
+    let relax = Relax {
+        density: Density::Raster {
+            data: &data,
+            width: 2,
+            height: 2,
+        },
+        ..Relax::default()
+    };
+    let points = vec![Coord { x: 0.5, y: 0.5 }];
+    let voronoi: VoronoiStub =
+        Delaunay::new(&points).voronoi(Some(Bounds::new(0., 0., 2., 2.)));
+    relax.centroids(&voronoi);
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "robust")]
+extern crate pretty_assertions;
+
+use delaunator::next_halfedge;
+use delaunator::prev_halfedge;
+use delaunator::EMPTY;
+use geo_types::Coord;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::delaunay::Delaunay;
+
+type DelaunayStub = Delaunay<f64>;
+
+// Not in the original, d3-delaunay has no exact predicates.
+
+fn exact(p: Coord<f64>) -> robust::Coord<f64> {
+    robust::Coord { x: p.x, y: p.y }
+}
+
+#[test]
+fn triangulation_is_exactly_delaunay() {
+    println!("with exact predicates every edge passes the in-circle test");
+    // A skewed grid of rounded tenths, where many points are nearly
+    // cocircular. Without the feature one edge fails.
+    let points: Vec<Coord<f64>> = (0..100)
+        .map(|i| {
+            let (a, b) = (f64::from(i % 10), f64::from(i / 10));
+            Coord {
+                x: 0.1 * a + 0.6 + 0.7 * b * 0.1,
+                y: 0.1 * b * 1.3,
+            }
+        })
+        .collect();
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    let triangles = &delaunay.delaunator.triangles;
+    let halfedges = &delaunay.delaunator.halfedges;
+    assert_eq!(triangles.len(), 3 * (2 * 100 - 2 - delaunay.hull().count()));
+    for t in triangles.chunks(3) {
+        let [a, b, c] = [t[0], t[1], t[2]].map(|i| exact(points[i]));
+        // robust orients with y pointing up, the triangles wind the other
+        // way.
+        assert!(robust::orient2d(a, c, b) > 0.);
+    }
+    for (a, b) in halfedges.iter().enumerate() {
+        if *b == EMPTY {
+            continue;
+        }
+        let [pr, pl, p0, p1] = [
+            triangles[a],
+            triangles[next_halfedge(a)],
+            triangles[prev_halfedge(a)],
+            triangles[prev_halfedge(*b)],
+        ]
+        .map(|i| exact(points[i]));
+        assert!(robust::incircle(pr, pl, p0, p1) >= 0.);
+    }
+}
+
+#[test]
+fn small_coordinates() {
+    println!("with exact predicates tiny triangles are not collinear");
+    // Points a fraction of a metre apart, in degrees.
+    let points = vec![
+        Coord { x: 0., y: 0. },
+        Coord { x: 1e-6, y: 0. },
+        Coord { x: 0., y: 1e-6 },
+        Coord { x: 1e-6, y: 2e-6 },
+    ];
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    assert!(delaunay.collinear.is_none());
+    assert_eq!(delaunay.delaunator.triangles.len(), 6);
+    assert_eq!(
+        delaunay.find(
+            &Coord {
+                x: 0.9e-6,
+                y: 1.9e-6
+            },
+            None
+        ),
+        3
+    );
+
+    // The circumcenters are not mistaken for midpoints.
+    let voronoi = delaunay.voronoi(None);
+    for (t, c) in voronoi.circumcenters.iter().enumerate() {
+        let triangles = &voronoi.delaunay.delaunator.triangles;
+        let d: Vec<f64> = (0..3)
+            .map(|k| {
+                let p = points[triangles[3 * t + k]];
+                (p.x - c.x).hypot(p.y - c.y)
+            })
+            .collect();
+        assert!((d[0] - d[1]).abs() < 1e-18 && (d[0] - d[2]).abs() < 1e-18);
+    }
+}
+
+#[test]
+fn exactly_collinear() {
+    println!("with exact predicates only exactly collinear points are");
+    let points: Vec<Coord<f64>> = (0..20)
+        .map(|i| Coord {
+            x: f64::from(i) * 4.,
+            y: f64::from(i) * 2. + 100.,
+        })
+        .collect();
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    assert!(delaunay.collinear.is_some());
+    assert_eq!(delaunay.neighbors(2).collect::<Vec<_>>(), vec![1, 3]);
+
+    // Thirds are rounded, so these points are not quite collinear.
+    let points: Vec<Coord<f64>> = (0..120)
+        .map(|i| Coord {
+            x: f64::from(i) * 4.,
+            y: f64::from(i) / 3. + 100.,
+        })
+        .collect();
+    let delaunay: DelaunayStub = Delaunay::new(&points);
+    assert!(delaunay.collinear.is_none());
+}
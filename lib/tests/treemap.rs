@@ -0,0 +1,122 @@
+extern crate pretty_assertions;
+
+use geo::Area;
+use geo::Distance;
+use geo::Euclidean;
+use geo_types::polygon;
+use geo_types::Polygon;
+use geo_types::Rect;
+use pretty_assertions::assert_eq;
+
+use d3_delaunay_rs::error::DelaunayError;
+use d3_delaunay_rs::treemap::Node;
+use d3_delaunay_rs::treemap::Treemap;
+
+type TreemapStub = Treemap<f64>;
+
+// Not in the original, the treemap module has no javascript equivalent.
+
+#[test]
+fn areas_are_proportional_to_values() {
+    println!("layout() gives each leaf a share of the region in proportion to its value");
+    let leaves: Vec<Node<f64>> = (1..=12)
+        .map(|i| Node::leaf(i, f64::from(i as u8 % 5 + 1)))
+        .collect();
+    let root = Node::new(0, leaves.clone());
+    let region = Rect::new((0., 0.), (200., 100.)).to_polygon();
+    let treemap = TreemapStub::default();
+    let cells = treemap.layout(&root, &region).unwrap();
+
+    assert_eq!(cells.len(), 13);
+    assert_eq!(cells[&0], region);
+    let error: f64 = leaves
+        .iter()
+        .map(|leaf| {
+            let target = 20_000. * leaf.value / root.value;
+            (cells[&leaf.id].unsigned_area() - target).abs()
+        })
+        .sum();
+    assert!(error <= 20_000. * treemap.tolerance, "{error}");
+    let total: f64 = leaves.iter().map(|l| cells[&l.id].unsigned_area()).sum();
+    assert!((total - 20_000.).abs() < 1e-6);
+}
+
+#[test]
+fn nested_cells_lie_within_their_parent() {
+    println!("layout() shares out the cell of each node among its children");
+    let root = Node::new(
+        0,
+        vec![
+            Node::new(1, vec![Node::leaf(3, 1.), Node::leaf(4, 2.)]),
+            Node::new(
+                2,
+                vec![Node::leaf(5, 1.), Node::leaf(6, 1.), Node::leaf(7, 1.)],
+            ),
+        ],
+    );
+    let region: Polygon<f64> =
+        polygon![(x: 0., y: 0.), (x: 120., y: 0.), (x: 40., y: 90.)];
+    let treemap = TreemapStub {
+        iterations: 500,
+        tolerance: 1e-9,
+    };
+    let cells = treemap.layout(&root, &region).unwrap();
+    assert_eq!(cells.len(), 8);
+
+    let area = region.unsigned_area();
+    assert!((cells[&1].unsigned_area() - area * 3. / 6.).abs() < 1e-3);
+    assert!((cells[&2].unsigned_area() - area * 3. / 6.).abs() < 1e-3);
+    assert!((cells[&4].unsigned_area() - area * 2. / 6.).abs() < 1e-3);
+    assert!((cells[&6].unsigned_area() - area / 6.).abs() < 1e-3);
+    for (parent, children) in [(1, vec![3, 4]), (2, vec![5, 6, 7])] {
+        let parent = &cells[&parent];
+        let sum: f64 = children.iter().map(|c| cells[c].unsigned_area()).sum();
+        assert!((sum - parent.unsigned_area()).abs() < 1e-6);
+        for c in children {
+            for p in cells[&c].exterior().points() {
+                assert!(Euclidean.distance(&p, parent) < 1e-9, "{p:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn zero_values_are_left_out() {
+    println!("layout() leaves out nodes without a value");
+    let root = Node::new(
+        0,
+        vec![
+            Node::leaf(1, 1.),
+            Node::leaf(2, 0.),
+            Node::new(3, vec![Node::leaf(4, 0.)]),
+        ],
+    );
+    let region = Rect::new((0., 0.), (10., 10.)).to_polygon();
+    let cells = TreemapStub::default().layout(&root, &region).unwrap();
+    let mut ids: Vec<usize> = cells.keys().copied().collect();
+    ids.sort_unstable();
+    assert_eq!(ids, vec![0, 1]);
+    assert_eq!(cells[&1], region);
+}
+
+#[test]
+fn layout_rejects_invalid_input() {
+    println!("layout() reports invalid values and regions");
+    let region = Rect::new((0., 0.), (10., 10.)).to_polygon();
+    let root = Node::new(0, vec![Node::leaf(1, 1.), Node::leaf(2, -1.)]);
+    assert_eq!(
+        TreemapStub::default().layout(&root, &region).unwrap_err(),
+        DelaunayError::InvalidValue(2)
+    );
+    let root = Node::new(0, vec![Node::leaf(1, 1.), Node::leaf(2, f64::NAN)]);
+    assert_eq!(
+        TreemapStub::default().layout(&root, &region).unwrap_err(),
+        DelaunayError::InvalidValue(2)
+    );
+    let line: Polygon<f64> = polygon![(x: 0., y: 0.), (x: 10., y: 0.)];
+    let root = Node::new(0, vec![Node::leaf(1, 1.)]);
+    assert_eq!(
+        TreemapStub::default().layout(&root, &line).unwrap_err(),
+        DelaunayError::InvalidRegion
+    );
+}
@@ -4,6 +4,7 @@ extern crate pretty_assertions;
 use geo_types::Coord;
 use pretty_assertions::assert_eq;
 
+use d3_delaunay_rs::bounds::Bounds;
 use d3_delaunay_rs::delaunay::Delaunay;
 use d3_delaunay_rs::path::Path;
 use d3_delaunay_rs::voronoi::Voronoi;
@@ -41,8 +42,8 @@ fn noop_for_coincident_points() {
         Coord { x: 0f64, y: 1f64 },
         Coord { x: 1f64, y: 0f64 },
     ];
-    let voronoi: VoronoiStub =
-        Delaunay::new(&points).voronoi(Some((-1f64, -1f64, 2f64, 2f64)));
+    let voronoi: VoronoiStub = Delaunay::new(&points)
+        .voronoi(Some(Bounds::new(-1f64, -1f64, 2f64, 2f64)));
     let mut path = Path::default();
     voronoi.render_cell(3, &mut path);
     assert_eq!(path.to_string(), String::from(""));
@@ -56,8 +57,8 @@ fn render_cell_midpoint() {
         Coord { x: 1f64, y: 0f64 },
         Coord { x: 0f64, y: 1f64 },
     ];
-    let voronoi: VoronoiStub =
-        Delaunay::new(&points).voronoi(Some((-1f64, -1f64, 2f64, 2f64)));
+    let voronoi: VoronoiStub = Delaunay::new(&points)
+        .voronoi(Some(Bounds::new(-1f64, -1f64, 2f64, 2f64)));
     let mut context1 = Path::default();
     {
         voronoi.render_cell(0, &mut context1);
@@ -83,8 +84,8 @@ fn contains_false_for_coincident() {
         Coord { x: 1_f64, y: 0_f64 },
     ];
 
-    let voronoi: VoronoiStub =
-        Delaunay::new(&points).voronoi(Some((-1_f64, -1_f64, 2_f64, 2_f64)));
+    let voronoi: VoronoiStub = Delaunay::new(&points)
+        .voronoi(Some(Bounds::new(-1_f64, -1_f64, 2_f64, 2_f64)));
     assert_eq!(voronoi.contains(3, &Coord { x: 1_f64, y: 0_f64 }), false);
     assert_eq!(voronoi.contains(1, &Coord { x: 1_f64, y: 0_f64 }), true);
 }
@@ -112,6 +113,154 @@ fn contains_false_for_coincident() {
 //   test.deepEqual(p, [[-500, 500], [-500, -140], [-240, -140], [-140, 60], [-140, 500], [-500, 500]]);
 // });
 
+#[test]
+fn update_updates_the_voronoi() {
+    println!("voronoi.update() updates the voronoi");
+    let points = vec![
+        Coord { x: 0_f64, y: 0_f64 },
+        Coord {
+            x: 300_f64,
+            y: 0_f64,
+        },
+        Coord {
+            x: 0_f64,
+            y: 300_f64,
+        },
+        Coord {
+            x: 300_f64,
+            y: 300_f64,
+        },
+        Coord {
+            x: 100_f64,
+            y: 100_f64,
+        },
+    ];
+    let mut voronoi: VoronoiStub = Delaunay::new(&points)
+        .voronoi(Some(Bounds::new(-500_f64, -500_f64, 500_f64, 500_f64)));
+    for p in &mut voronoi.delaunay.points {
+        p.x = 10_f64 - p.x;
+        p.y = 10_f64 - p.y;
+    }
+    let p = voronoi.update().cell_polygon(1);
+    assert_eq!(
+        p,
+        vec![
+            Coord {
+                x: -500_f64,
+                y: 500_f64
+            },
+            Coord {
+                x: -500_f64,
+                y: -140_f64
+            },
+            Coord {
+                x: -240_f64,
+                y: -140_f64
+            },
+            Coord {
+                x: -140_f64,
+                y: 60_f64
+            },
+            Coord {
+                x: -140_f64,
+                y: 500_f64
+            },
+            Coord {
+                x: -500_f64,
+                y: 500_f64
+            },
+        ]
+    );
+}
+
+#[test]
+fn update_updates_a_degenerate_voronoi() {
+    println!("voronoi.update() updates a degenerate voronoi");
+    let pts = [
+        Coord {
+            x: 10_f64,
+            y: 10_f64,
+        },
+        Coord {
+            x: -290_f64,
+            y: 10_f64,
+        },
+        Coord {
+            x: 10_f64,
+            y: -290_f64,
+        },
+        Coord {
+            x: -290_f64,
+            y: -290_f64,
+        },
+        Coord {
+            x: -90_f64,
+            y: -90_f64,
+        },
+    ];
+    let mut voronoi: VoronoiStub =
+        Delaunay::new(&[Coord { x: 0_f64, y: 0_f64 }; 5])
+            .voronoi(Some(Bounds::new(-500_f64, -500_f64, 500_f64, 500_f64)));
+    assert_eq!(
+        voronoi.cell_polygon(0),
+        vec![
+            Coord {
+                x: 500_f64,
+                y: -500_f64
+            },
+            Coord {
+                x: 500_f64,
+                y: 500_f64
+            },
+            Coord {
+                x: -500_f64,
+                y: 500_f64
+            },
+            Coord {
+                x: -500_f64,
+                y: -500_f64
+            },
+            Coord {
+                x: 500_f64,
+                y: -500_f64
+            },
+        ]
+    );
+    assert!(voronoi.cell_polygon(1).is_empty());
+
+    voronoi.delaunay.points.copy_from_slice(&pts);
+    let p = voronoi.update().cell_polygon(1);
+    assert_eq!(
+        p,
+        vec![
+            Coord {
+                x: -500_f64,
+                y: 500_f64
+            },
+            Coord {
+                x: -500_f64,
+                y: -140_f64
+            },
+            Coord {
+                x: -240_f64,
+                y: -140_f64
+            },
+            Coord {
+                x: -140_f64,
+                y: 60_f64
+            },
+            Coord {
+                x: -140_f64,
+                y: 500_f64
+            },
+            Coord {
+                x: -500_f64,
+                y: 500_f64
+            },
+        ]
+    );
+}
+
 #[test]
 fn zero_length_edges_are_removed() {
     println!("zero-length edges are removed");
@@ -133,7 +282,7 @@ fn zero_length_edges_are_removed() {
             y: 100.0f64,
         },
     ])
-    .voronoi(Some((40f64, 40f64, 440f64, 180f64)));
+    .voronoi(Some(Bounds::new(40f64, 40f64, 440f64, 180f64)));
     assert_eq!(voronoi1.cell_polygon(0).len(), 4);
 
     let voronoi2: VoronoiStub = Delaunay::new(&[
@@ -146,7 +295,7 @@ fn zero_length_edges_are_removed() {
             y: 10.0f64,
         },
     ])
-    .voronoi(Some((0f64, 0f64, 30f64, 20f64)));
+    .voronoi(Some(Bounds::new(0f64, 0f64, 30f64, 20f64)));
 
     assert_eq!(
         voronoi2.cell_polygon(0),
@@ -185,6 +334,50 @@ fn zero_length_edges_are_removed() {
 //    test.deepEqual([...voronoi.neighbors(5)].sort(), []);
 // });
 
+#[test]
+fn neighbors_are_clipped() {
+    println!("voronoi neighbors are clipped");
+    let points = vec![
+        Coord {
+            x: 300_f64,
+            y: 10_f64,
+        },
+        Coord {
+            x: 200_f64,
+            y: 100_f64,
+        },
+        Coord {
+            x: 300_f64,
+            y: 100_f64,
+        },
+        Coord {
+            x: 10_f64,
+            y: 10_f64,
+        },
+        Coord {
+            x: 350_f64,
+            y: 200_f64,
+        },
+        Coord {
+            x: 350_f64,
+            y: 400_f64,
+        },
+    ];
+    let voronoi: VoronoiStub = Delaunay::new(&points)
+        .voronoi(Some(Bounds::new(0_f64, 0_f64, 500_f64, 150_f64)));
+    let sorted = |i| {
+        let mut n = voronoi.neighbors(i).collect::<Vec<_>>();
+        n.sort_unstable();
+        n
+    };
+    assert_eq!(sorted(0), vec![1, 2]);
+    assert_eq!(sorted(1), vec![0, 2]);
+    assert_eq!(sorted(2), vec![0, 1, 4]);
+    assert_eq!(sorted(3), vec![]);
+    assert_eq!(sorted(4), vec![2]);
+    assert_eq!(sorted(5), vec![]);
+}
+
 // tape("unnecessary points on the corners are avoided (#88)", test => {
 //   for (const [points, lengths] of [
 //     [ [[289,25],[3,22],[93,165],[282,184],[65,89]], [ 6, 4, 6, 5, 6 ] ],
@@ -196,37 +389,54 @@ fn zero_length_edges_are_removed() {
 //   }
 // });
 
-// fn unnecessary_point_on_the_corner() {
-//     println!("unnecessary points on the corners are avoided (#88)");
-//     let pattern = vec![(
-//         vec![
-//             Coord {
-//                 x: 289f64,
-//                 y: 25f64,
-//             },
-//             Coord { x: 3f64, y: 22f64 },
-//             Coord {
-//                 x: 93f64,
-//                 y: 165f64,
-//             },
-//             Coord {
-//                 x: 282f64,
-//                 y: 184f64,
-//             },
-//             Coord { x: 65f64, y: 89f64 },
-//         ],
-//         vec![6, 4, 6, 5, 6],
-//     )];
-
-//     for (coords, len_array) in pattern {
-//         let voronoi = Delaunay::new(coords).voronoi(Some((0., 0., 290., 190.)));
-
-//         for i in 0..len_array.len() {
-//             let cell_array = voronoi.cell_polygon(i);
-//             cell_array.map(|d| d.length).collect();
-//         }
-//     }
-// }
+#[test]
+fn unnecessary_points_on_the_corners_are_avoided() {
+    println!("unnecessary points on the corners are avoided (#88)");
+    let patterns = vec![
+        (
+            vec![
+                (289., 25.),
+                (3., 22.),
+                (93., 165.),
+                (282., 184.),
+                (65., 89.),
+            ],
+            vec![6, 4, 6, 5, 6],
+        ),
+        (
+            vec![
+                (189., 13.),
+                (197., 26.),
+                (47., 133.),
+                (125., 77.),
+                (288., 15.),
+            ],
+            vec![4, 6, 5, 6, 5],
+        ),
+        (
+            vec![
+                (44., 42.),
+                (210., 193.),
+                (113., 103.),
+                (185., 43.),
+                (184., 37.),
+            ],
+            vec![5, 5, 7, 5, 6],
+        ),
+    ];
+
+    for (points, lengths) in patterns {
+        let points: Vec<Coord<f64>> =
+            points.into_iter().map(|(x, y)| Coord { x, y }).collect();
+        let voronoi: VoronoiStub = Delaunay::new(&points)
+            .voronoi(Some(Bounds::new(0., 0., 290., 190.)));
+        let cell_lengths: Vec<usize> = voronoi
+            .cell_polygons()
+            .map(|(_, cell)| cell.exterior().0.len())
+            .collect();
+        assert_eq!(cell_lengths, lengths);
+    }
+}
 
 // tape("a degenerate triangle is avoided", test => {
 //   const pts = [[424.75, 253.75],[424.75, 253.74999999999997],[407.17640687119285, 296.17640687119285],[364.75, 313.75],[322.32359312880715, 296.17640687119285],[304.75, 253.75],[322.32359312880715, 211.32359312880715],[364.75, 193.75],[407.17640687119285, 211.32359312880715],[624.75, 253.75],[607.1764068711929, 296.17640687119285],[564.75, 313.75],[522.3235931288071, 296.17640687119285],[504.75, 253.75],[564.75, 193.75]
@@ -300,7 +510,7 @@ fn a_degenerate_triangle_is_avoided() {
         },
     ];
     let d: DelaunayStub = Delaunay::new(&pts);
-    let voronoi = d.voronoi(Some((10., 10., 960., 500.)));
+    let voronoi = d.voronoi(Some(Bounds::new(10., 10., 960., 500.)));
     assert_eq!(voronoi.cell_polygon(0).len(), 4);
 }
 // tape("cellPolygons filter out empty cells and have the cell index as a property", test => {
@@ -312,6 +522,48 @@ fn a_degenerate_triangle_is_avoided() {
 //   ]);
 // });
 
+#[test]
+fn cell_polygons_filter_out_empty_cells() {
+    println!("cellPolygons filter out empty cells and have the cell index as a property");
+    let pts = vec![
+        Coord { x: 0., y: 0. },
+        Coord { x: 3., y: 3. },
+        Coord { x: 1., y: 1. },
+        Coord { x: -3., y: -2. },
+    ];
+    let voronoi: VoronoiStub =
+        Delaunay::new(&pts).voronoi(Some(Bounds::new(0., 0., 2., 2.)));
+    let cells: Vec<(usize, Vec<Coord<f64>>)> = voronoi
+        .cell_polygons()
+        .map(|(i, cell)| (i, cell.exterior().0.clone()))
+        .collect();
+    assert_eq!(
+        cells,
+        vec![
+            (
+                0,
+                vec![
+                    Coord { x: 0., y: 0. },
+                    Coord { x: 1., y: 0. },
+                    Coord { x: 0., y: 1. },
+                    Coord { x: 0., y: 0. },
+                ]
+            ),
+            (
+                2,
+                vec![
+                    Coord { x: 0., y: 1. },
+                    Coord { x: 1., y: 0. },
+                    Coord { x: 2., y: 0. },
+                    Coord { x: 2., y: 2. },
+                    Coord { x: 0., y: 2. },
+                    Coord { x: 0., y: 1. },
+                ]
+            ),
+        ]
+    );
+}
+
 // This test in not in the original
 //
 // it("pattern produces a cross", () => {
@@ -373,7 +625,8 @@ fn generates_a_cross_pattern() {
                 "M25,75L50,50M50,50L25,25M75,75L50,50M50,50L75,25M75,75L75,25L25,25L25,75Z"
         );
 
-    let voronoi = delaunay.voronoi(Some((0_f64, 0_f64, 100_f64, 100_f64)));
+    let voronoi =
+        delaunay.voronoi(Some(Bounds::new(0_f64, 0_f64, 100_f64, 100_f64)));
 
     assert_eq!(
             voronoi.render_to_string(),
@@ -425,10 +678,102 @@ fn almost_colinear_triangle() {
 
     assert_eq!(delaunay.render_to_string(), "M33,85L90,73L7,87Z");
 
-    let voronoi = delaunay.voronoi(Some((0_f64, 0_f64, 100_f64, 100_f64)));
+    let voronoi =
+        delaunay.voronoi(Some(Bounds::new(0_f64, 0_f64, 100_f64, 100_f64)));
 
     assert_eq!(
         voronoi.render_to_string(),
            "M13.384615384615387,0L21.07692307692308,100M44.8684210526316,0L65.92105263157896,100"
     );
 }
+
+// Not in the original.
+#[test]
+fn natural_neighbors_have_linear_precision() {
+    println!("natural neighbor coordinates reproduce the query point");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 10f64, y: 0f64 },
+        Coord { x: 10f64, y: 10f64 },
+        Coord { x: 0f64, y: 10f64 },
+        Coord { x: 3f64, y: 4f64 },
+        Coord { x: 7f64, y: 2f64 },
+        Coord { x: 6f64, y: 7f64 },
+        Coord { x: 2f64, y: 8f64 },
+        Coord { x: 8f64, y: 5f64 },
+    ];
+    let voronoi: VoronoiStub = Delaunay::new(&points).voronoi(None);
+    for p in [
+        Coord { x: 5., y: 5. },
+        Coord { x: 1., y: 1. },
+        Coord { x: 9.5, y: 3. },
+        Coord { x: 4.2, y: 7.1 },
+        // On the hull.
+        Coord { x: 10., y: 4. },
+    ] {
+        let neighbors = voronoi.natural_neighbors(&p).unwrap();
+        assert!(neighbors.iter().all(|(_, w)| *w > 0.), "{neighbors:?}");
+        let total: f64 = neighbors.iter().map(|(_, w)| w).sum();
+        assert!((total - 1.).abs() < 1e-12);
+        let q = neighbors
+            .iter()
+            .fold(Coord::zero(), |q, (i, w)| q + points[*i] * *w);
+        assert!((q.x - p.x).abs() < 1e-9 && (q.y - p.y).abs() < 1e-9);
+        // The same order, and so the same sums, every time.
+        for _ in 0..10 {
+            assert_eq!(voronoi.natural_neighbors(&p).unwrap(), neighbors);
+        }
+    }
+
+    // At a point.
+    assert_eq!(
+        voronoi.natural_neighbors(&Coord { x: 3., y: 4. }),
+        Some(vec![(4, 1.)])
+    );
+    assert_eq!(voronoi.natural_neighbors(&Coord { x: 11., y: 4. }), None);
+}
+
+// Not in the original.
+#[test]
+fn interpolate_natural() {
+    println!("voronoi.interpolate_natural() is exact for a plane");
+    let points = vec![
+        Coord { x: 0f64, y: 0f64 },
+        Coord { x: 4f64, y: 0f64 },
+        Coord { x: 4f64, y: 4f64 },
+        Coord { x: 0f64, y: 4f64 },
+        Coord { x: 1f64, y: 1.5f64 },
+        Coord { x: 2.5f64, y: 3f64 },
+    ];
+    let plane = |p: &Coord<f64>| 3. * p.x + p.y - 2.;
+    let values: Vec<f64> = points.iter().map(plane).collect();
+    let voronoi: VoronoiStub = Delaunay::new(&points).voronoi(None);
+
+    let grid = voronoi.interpolate_natural_grid(
+        &values,
+        &Bounds::new(0., 0., 5., 4.),
+        10,
+        8,
+    );
+    assert_eq!(grid.len(), 80);
+    for (k, v) in grid.iter().enumerate() {
+        let p = Coord {
+            x: (k % 10) as f64 / 2. + 0.25,
+            y: (k / 10) as f64 / 2. + 0.25,
+        };
+        if p.x < 4. {
+            assert!((v.unwrap() - plane(&p)).abs() < 1e-9, "{p:?}");
+            let single = voronoi.interpolate_natural(&values, &p).unwrap();
+            assert!((v.unwrap() - single).abs() < 1e-12);
+        } else {
+            assert_eq!(*v, None);
+        }
+    }
+
+    // Unlike linear interpolation the surface is curved.
+    let bump: Vec<f64> = (0..points.len()).map(|i| f64::from(i == 4)).collect();
+    let p = Coord { x: 1.5, y: 1.5 };
+    let natural = voronoi.interpolate_natural(&bump, &p).unwrap();
+    let linear = voronoi.delaunay.interpolate_linear(&bump, &p).unwrap();
+    assert!((natural - linear).abs() > 1e-3);
+}